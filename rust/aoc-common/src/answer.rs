@@ -0,0 +1,209 @@
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Mul, MulAssign};
+
+use num_bigint::BigInt;
+
+/// A puzzle's answer. Arithmetic on answers never wraps: a result too big
+/// for 64 bits becomes [`Answer::Big`], and one that fits again goes back to
+/// a machine integer, so equal numbers always compare equal.
+///
+/// Numbers are kept in the smallest variant that holds them, with
+/// [`Answer::Signed`] only for negative ones.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Big(BigInt),
+    Text(String),
+}
+
+impl Answer {
+    fn to_big(&self, op: &str) -> BigInt {
+        match self {
+            Answer::Unsigned(n) => BigInt::from(*n),
+            Answer::Signed(n) => BigInt::from(*n),
+            Answer::Big(n) => n.clone(),
+            Answer::Text(text) => panic!("Can't {} the text answer {:?}", op, text),
+        }
+    }
+}
+
+impl Default for Answer {
+    fn default() -> Self {
+        Answer::Unsigned(0)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Big(n) => write!(f, "{}", n),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Self {
+        if let Ok(n) = u64::try_from(&n) {
+            Answer::Unsigned(n)
+        } else if let Ok(n) = i64::try_from(&n) {
+            Answer::Signed(n)
+        } else {
+            Answer::Big(n)
+        }
+    }
+}
+
+macro_rules! from_unsigned {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Unsigned(n as u64)
+            }
+        }
+    )*};
+}
+
+macro_rules! from_signed {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                match u64::try_from(n) {
+                    Ok(n) => Answer::Unsigned(n),
+                    Err(_) => Answer::Signed(n as i64),
+                }
+            }
+        }
+    )*};
+}
+
+from_unsigned!(u8, u16, u32, u64, usize);
+from_signed!(i8, i16, i32, i64, isize);
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        Answer::from(BigInt::from(n))
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(n: i128) -> Self {
+        Answer::from(BigInt::from(n))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(String::from(text))
+    }
+}
+
+/// # Panics
+///
+/// If either side is [`Answer::Text`].
+impl<T: Into<Answer>> Add<T> for Answer {
+    type Output = Answer;
+
+    fn add(self, other: T) -> Answer {
+        let other = other.into();
+        if let (Answer::Unsigned(a), Answer::Unsigned(b)) = (&self, &other)
+            && let Some(sum) = a.checked_add(*b)
+        {
+            return Answer::Unsigned(sum);
+        }
+        Answer::from(self.to_big("add") + other.to_big("add"))
+    }
+}
+
+/// # Panics
+///
+/// If either side is [`Answer::Text`].
+impl<T: Into<Answer>> Mul<T> for Answer {
+    type Output = Answer;
+
+    fn mul(self, other: T) -> Answer {
+        let other = other.into();
+        if let (Answer::Unsigned(a), Answer::Unsigned(b)) = (&self, &other)
+            && let Some(product) = a.checked_mul(*b)
+        {
+            return Answer::Unsigned(product);
+        }
+        Answer::from(self.to_big("multiply") * other.to_big("multiply"))
+    }
+}
+
+impl<T: Into<Answer>> AddAssign<T> for Answer {
+    fn add_assign(&mut self, other: T) {
+        *self = std::mem::take(self) + other;
+    }
+}
+
+impl<T: Into<Answer>> MulAssign<T> for Answer {
+    fn mul_assign(&mut self, other: T) {
+        *self = std::mem::take(self) * other;
+    }
+}
+
+impl<T: Into<Answer>> Sum<T> for Answer {
+    fn sum<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(Answer::from(0u64), |acc, n| acc + n)
+    }
+}
+
+impl<T: Into<Answer>> Product<T> for Answer {
+    fn product<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(Answer::from(1u64), |acc, n| acc * n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn promotes_instead_of_wrapping() {
+        let big = Answer::from(u64::MAX) + 1u64;
+        assert_eq!(big.to_string(), "18446744073709551616");
+        assert!(matches!(big, Answer::Big(_)));
+
+        let product = [u64::MAX, 3, 5].into_iter().product::<Answer>();
+        assert_eq!(product.to_string(), "276701161105643274225");
+    }
+
+    #[test]
+    fn narrows_back_down() {
+        let back = (Answer::from(u64::MAX) + 1u64) + -2i64;
+        assert_eq!(back, Answer::Unsigned(u64::MAX - 1));
+        assert_eq!(Answer::from(-4) * -1, Answer::Unsigned(4));
+        assert_eq!(Answer::from(3) + -5, Answer::Signed(-2));
+        assert_eq!(
+            Answer::from(i64::MIN) + -1,
+            Answer::from(i128::from(i64::MIN) - 1)
+        );
+    }
+
+    #[test]
+    fn sums() {
+        let mut total = Answer::default();
+        total += 7u32;
+        total += 8usize;
+        assert_eq!(total, Answer::from(15u64));
+        assert_eq!([1u64, 2, 3].into_iter().sum::<Answer>(), Answer::from(6));
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't add the text answer")]
+    fn no_arithmetic_on_text() {
+        let _ = Answer::from("merry") + 1;
+    }
+}
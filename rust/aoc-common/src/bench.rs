@@ -0,0 +1,285 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+
+use crate::cli::{Format, RunOptions};
+use crate::error::Error;
+use crate::input::load_input;
+use crate::runner::with_file;
+use crate::solution::Solution;
+use crate::table::Table;
+
+/// Options for timing a solution over many runs.
+#[derive(Args, Clone, Debug, Default)]
+pub struct BenchOptions {
+    /// Time parsing and each part over N runs instead of solving once
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    pub bench: Option<u32>,
+
+    /// Untimed runs of each phase before the benchmark starts
+    #[arg(long, value_name = "N", default_value_t = 1, requires = "bench")]
+    pub warmup: u32,
+
+    /// Compare the medians against a baseline saved earlier
+    #[arg(long, value_name = "PATH", requires = "bench")]
+    pub baseline: Option<PathBuf>,
+
+    /// Save the results as a baseline, keeping other days already in the file
+    #[arg(long, value_name = "PATH", requires = "bench")]
+    pub save_baseline: Option<PathBuf>,
+
+    /// How much slower than the baseline median counts as a regression, in percent
+    #[arg(
+        long,
+        value_name = "PERCENT",
+        default_value_t = 10.0,
+        requires = "baseline"
+    )]
+    pub regression_threshold: f64,
+}
+
+/// Summary statistics for one phase, in nanoseconds.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: f64,
+    pub median_ns: f64,
+    pub mean_ns: f64,
+    pub p95_ns: f64,
+    pub std_dev_ns: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty());
+
+        let mut nanos = samples
+            .iter()
+            .map(|sample| sample.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        nanos.sort_by(f64::total_cmp);
+
+        let n = nanos.len();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let median = if n % 2 == 0 {
+            (nanos[n / 2 - 1] + nanos[n / 2]) / 2.0
+        } else {
+            nanos[n / 2]
+        };
+        // nearest rank
+        let p95 = nanos[((0.95 * n as f64).ceil() as usize).max(1) - 1];
+        let variance = if n > 1 {
+            nanos.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        Stats {
+            min_ns: nanos[0],
+            median_ns: median,
+            mean_ns: mean,
+            p95_ns: p95,
+            std_dev_ns: variance.sqrt(),
+        }
+    }
+}
+
+/// Saved stats, keyed by day and then by phase.
+#[derive(Default, Serialize, Deserialize)]
+struct Baseline {
+    days: BTreeMap<String, BTreeMap<String, Stats>>,
+}
+
+impl Baseline {
+    fn load(path: &Path) -> Result<Self, Error> {
+        let contents = fs::read_to_string(path).map_err(|source| Error::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        serde_json::from_str(&contents).map_err(|err| Error::Read {
+            path: path.to_path_buf(),
+            source: err.into(),
+        })
+    }
+
+    fn save(&self, path: &Path) -> Result<(), Error> {
+        let contents = serde_json::to_string_pretty(self).expect("baselines always serialize");
+        fs::write(path, contents + "\n").map_err(|source| Error::Write {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct Phase {
+    name: &'static str,
+    #[serde(flatten)]
+    stats: Stats,
+    // relative change of the median against the baseline
+    #[serde(skip_serializing_if = "Option::is_none")]
+    change: Option<f64>,
+    regressed: bool,
+}
+
+#[derive(Serialize)]
+struct BenchReport<'a> {
+    day: u32,
+    title: &'static str,
+    input: &'a Path,
+    runs: u32,
+    warmup: u32,
+    phases: Vec<Phase>,
+}
+
+fn sample<T>(runs: u32, warmup: u32, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..warmup {
+        black_box(f());
+    }
+
+    let samples = (0..runs)
+        .map(|_| {
+            let now = Instant::now();
+            black_box(f());
+            now.elapsed()
+        })
+        .collect::<Vec<_>>();
+
+    Stats::from_samples(&samples)
+}
+
+pub fn format_duration(nanos: f64) -> String {
+    if nanos < 1e3 {
+        format!("{:.0}ns", nanos)
+    } else if nanos < 1e6 {
+        format!("{:.2}µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.2}ms", nanos / 1e6)
+    } else {
+        format!("{:.2}s", nanos / 1e9)
+    }
+}
+
+/// Times parsing and each part of the solution separately, comparing against
+/// and saving baselines as asked.
+pub(crate) fn bench<S: Solution>(input_path: &Path, options: &RunOptions) -> Result<(), Error> {
+    let bench = &options.bench;
+    let runs = bench.bench.unwrap_or(1);
+    let warmup = bench.warmup;
+
+    let input = load_input(input_path)?;
+    let params = options.params();
+
+    // make sure the input is good before timing anything
+    let parsed = S::parse(&input).map_err(|err| err.with_file(input_path))?;
+    S::check(&input, &parsed, options.part, &params).map_err(|err| with_file(err, input_path))?;
+
+    let mut phases = vec![("parse", sample(runs, warmup, || S::parse(&input).is_ok()))];
+    if options.part.is_none_or(|part| part == 1) {
+        phases.push((
+            "part one",
+            sample(runs, warmup, || S::part_one(&parsed, &params)),
+        ));
+    }
+    if options.part.is_none_or(|part| part == 2) && S::part_two(&parsed, &params).is_some() {
+        phases.push((
+            "part two",
+            sample(runs, warmup, || S::part_two(&parsed, &params)),
+        ));
+    }
+
+    let key = format!("day{:02}", S::DAY);
+    let baseline = match &bench.baseline {
+        Some(path) => Baseline::load(path)?.days.remove(&key).unwrap_or_default(),
+        None => BTreeMap::new(),
+    };
+
+    let phases = phases
+        .into_iter()
+        .map(|(name, stats)| {
+            let change = baseline
+                .get(name)
+                .map(|base| stats.median_ns / base.median_ns - 1.0);
+            Phase {
+                name,
+                stats,
+                change,
+                regressed: change.is_some_and(|change| change * 100.0 > bench.regression_threshold),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(path) = &bench.save_baseline {
+        let mut saved = if path.exists() {
+            Baseline::load(path)?
+        } else {
+            Baseline::default()
+        };
+        saved.days.insert(
+            key,
+            phases
+                .iter()
+                .map(|phase| (String::from(phase.name), phase.stats.clone()))
+                .collect(),
+        );
+        saved.save(path)?;
+    }
+
+    let regressed = phases.iter().filter(|phase| phase.regressed).count();
+
+    let report = BenchReport {
+        day: S::DAY,
+        title: S::TITLE,
+        input: input_path,
+        runs,
+        warmup,
+        phases,
+    };
+
+    match options.format {
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string(&report).expect("reports always serialize")
+        ),
+        Format::Text => print_report(&report),
+    }
+
+    if regressed > 0 {
+        Err(Error::Regressed { phases: regressed })
+    } else {
+        Ok(())
+    }
+}
+
+fn print_report(report: &BenchReport) {
+    println!("Day {}: {}", report.day, report.title);
+    println!(
+        "Benchmark: {} runs after {} warm-up",
+        report.runs, report.warmup
+    );
+
+    let mut table = Table::new(&[
+        "Phase", "Min", "Median", "Mean", "p95", "Std dev", "Baseline",
+    ]);
+    for phase in report.phases.iter() {
+        let change = match phase.change {
+            Some(change) if phase.regressed => format!("{:+.1}% regressed", change * 100.0),
+            Some(change) => format!("{:+.1}%", change * 100.0),
+            None => String::new(),
+        };
+        table.push(vec![
+            String::from(phase.name),
+            format_duration(phase.stats.min_ns),
+            format_duration(phase.stats.median_ns),
+            format_duration(phase.stats.mean_ns),
+            format_duration(phase.stats.p95_ns),
+            format_duration(phase.stats.std_dev_ns),
+            change,
+        ]);
+    }
+    print!("{}", table);
+}
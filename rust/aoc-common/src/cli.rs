@@ -0,0 +1,121 @@
+use std::path::PathBuf;
+use std::process;
+
+use clap::{Args, CommandFactory, FromArgMatches, Parser, ValueEnum};
+use serde::Deserialize;
+
+use crate::bench::BenchOptions;
+use crate::config::Config;
+use crate::input::DEFAULT_FILEPATH;
+use crate::logging::Verbosity;
+use crate::params::{Params, PuzzleOptions, parse_param};
+use crate::runner::{print_header, run};
+use crate::solution::Solution;
+use crate::trace::TraceOptions;
+
+/// How answers and timings are printed.
+#[derive(ValueEnum, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// Human readable text
+    #[default]
+    Text,
+    /// One JSON object per line, with timings in microseconds
+    Json,
+}
+
+/// Options shared by every way of running a solution.
+#[derive(Args, Clone, Debug, Default)]
+pub struct RunOptions {
+    /// Only solve the given part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+
+    /// Puzzle parameter as NAME=VALUE, may be repeated
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+    pub params: Vec<(String, String)>,
+
+    /// Parameters from aoc.toml, which any given on the command line
+    /// override.
+    #[arg(skip)]
+    pub default_params: Vec<(String, String)>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
+
+    /// Solve while reading the input, without holding it all in memory, for
+    /// the days that can
+    #[arg(long, conflicts_with_all = ["bench", "trace", "visits", "trace_csv"])]
+    pub stream: bool,
+
+    #[command(flatten)]
+    pub bench: BenchOptions,
+
+    #[command(flatten)]
+    pub trace: TraceOptions,
+
+    #[command(flatten)]
+    pub puzzle: PuzzleOptions,
+}
+
+impl RunOptions {
+    /// Every puzzle parameter given, the config's first, then any `--param`,
+    /// then the flags.
+    pub fn param_pairs(&self) -> impl Iterator<Item = (String, String)> {
+        self.default_params
+            .iter()
+            .cloned()
+            .chain(self.command_line_params())
+    }
+
+    /// The puzzle parameters given on the command line.
+    pub fn command_line_params(&self) -> impl Iterator<Item = (String, String)> {
+        self.params.iter().cloned().chain(self.puzzle.params())
+    }
+
+    pub fn params(&self) -> Params {
+        Params::new(self.param_pairs())
+    }
+}
+
+#[derive(Parser)]
+struct DayCli {
+    /// Path to the puzzle input, - for stdin, or a directory of inputs
+    /// [default: from aoc.toml, or ./input/input.txt]
+    input: Option<PathBuf>,
+
+    /// The aoc.toml profile to take inputs and settings from
+    #[arg(long)]
+    profile: Option<String>,
+
+    #[command(flatten)]
+    verbosity: Verbosity,
+
+    #[command(flatten)]
+    options: RunOptions,
+}
+
+/// Entry point for a day's own binary.
+pub fn main<S: Solution>() {
+    let matches = DayCli::command().get_matches();
+    let mut cli = DayCli::from_arg_matches(&matches).unwrap_or_else(|err| err.exit());
+    cli.verbosity.init();
+
+    let config = Config::load(cli.profile.as_deref()).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        process::exit(1);
+    });
+    config.apply(&mut cli.options, &matches);
+    cli.options.default_params = config.params(S::DAY);
+    let input = cli
+        .input
+        .or_else(|| config.input(S::DAY))
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_FILEPATH));
+
+    print_header(&cli.options);
+    if let Err(err) = run::<S>(&input, &cli.options) {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
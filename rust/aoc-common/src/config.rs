@@ -0,0 +1,367 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::ArgMatches;
+use clap::parser::ValueSource;
+use serde::Deserialize;
+
+use crate::cli::{Format, RunOptions};
+use crate::error::Error;
+use crate::verify::value_to_string;
+
+/// The project config, looked for in the current directory and then each
+/// one above it.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Settings for one day.
+#[derive(Clone, Debug, Default, Deserialize)]
+struct DaySettings {
+    input: Option<String>,
+    /// An input for part two alone, for a day whose parts have separate
+    /// examples.
+    part_two_input: Option<String>,
+    #[serde(default)]
+    params: BTreeMap<String, toml::Value>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+struct BenchSettings {
+    warmup: Option<u32>,
+    regression_threshold: Option<f64>,
+    baseline: Option<String>,
+}
+
+/// Settings at the top of the file, or in a profile laid over them.
+#[derive(Clone, Debug, Default, Deserialize)]
+struct Settings {
+    /// Where each day's input is, with `{day}` for the two digit day.
+    input: Option<String>,
+    format: Option<Format>,
+    #[serde(default)]
+    bench: BenchSettings,
+    #[serde(default)]
+    day: BTreeMap<String, DaySettings>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ConfigFile {
+    /// The profile used when none is asked for.
+    profile: Option<String>,
+    #[serde(flatten)]
+    base: Settings,
+    #[serde(default)]
+    profiles: BTreeMap<String, Settings>,
+}
+
+/// The settings from `aoc.toml` for the chosen profile. Paths in it are
+/// relative to the file, so they work from any directory.
+#[derive(Debug, Default)]
+pub struct Config {
+    dir: PathBuf,
+    /// The top of the file, then the profile, each overriding the last.
+    layers: Vec<Settings>,
+}
+
+fn invalid(path: &Path, key: Option<String>, problem: String) -> Error {
+    Error::Config {
+        path: path.to_path_buf(),
+        key,
+        problem,
+    }
+}
+
+impl Config {
+    /// Finds `aoc.toml` and loads it with `profile`, or with the profile the
+    /// file chooses. With no file there is nothing to load, and every
+    /// setting keeps its default.
+    pub fn load(profile: Option<&str>) -> Result<Self, Error> {
+        let cwd = env::current_dir().unwrap_or_default();
+        match cwd
+            .ancestors()
+            .map(|dir| dir.join(CONFIG_FILE))
+            .find(|path| path.is_file())
+        {
+            Some(path) => Config::from_file(&path, profile),
+            None => match profile {
+                Some(profile) => Err(invalid(
+                    &cwd.join(CONFIG_FILE),
+                    Some(String::from("profile")),
+                    format!("no config file to find profile {} in", profile),
+                )),
+                None => Ok(Config::default()),
+            },
+        }
+    }
+
+    pub fn from_file(path: &Path, profile: Option<&str>) -> Result<Self, Error> {
+        let contents = fs::read_to_string(path).map_err(|source| Error::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let mut file: ConfigFile =
+            toml::from_str(&contents).map_err(|err| invalid(path, None, err.to_string()))?;
+
+        // each layer with the table its days are under, to name in errors
+        let mut layers = vec![(String::from("day"), file.base)];
+        if let Some(name) = profile.map(String::from).or(file.profile) {
+            let settings = file.profiles.remove(&name).ok_or_else(|| {
+                let known = file.profiles.keys().cloned().collect::<Vec<_>>();
+                invalid(
+                    path,
+                    Some(String::from("profile")),
+                    format!("no profile {}, only {}", name, known.join(", ")),
+                )
+            })?;
+            layers.push((format!("profiles.{}.day", name), settings));
+        }
+
+        // days may be written 8 or 08
+        for (table, settings) in layers.iter_mut() {
+            let days = std::mem::take(&mut settings.day);
+            for (key, day) in days {
+                let n = key
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|n| (1..=12).contains(n))
+                    .ok_or_else(|| {
+                        invalid(
+                            path,
+                            Some(format!("{}.{}", table, key)),
+                            format!("{} is not a day from 1 to 12", key),
+                        )
+                    })?;
+                settings.day.insert(n.to_string(), day);
+            }
+        }
+
+        Ok(Config {
+            dir: path.parent().unwrap_or(Path::new("")).to_path_buf(),
+            layers: layers.into_iter().map(|(_, settings)| settings).collect(),
+        })
+    }
+
+    fn day(&self, day: u32) -> impl Iterator<Item = &DaySettings> {
+        let key = day.to_string();
+        self.layers
+            .iter()
+            .filter_map(move |settings| settings.day.get(&key))
+    }
+
+    fn resolve(&self, path: &str) -> PathBuf {
+        self.dir.join(path)
+    }
+
+    /// The input the config gives for a day, if any. The profile beats the
+    /// top of the file, and within each a day's own input beats the pattern
+    /// for every day.
+    pub fn input(&self, day: u32) -> Option<PathBuf> {
+        let key = day.to_string();
+        let path = self.layers.iter().rev().find_map(|settings| {
+            let own = settings.day.get(&key).and_then(|d| d.input.as_ref());
+            own.or(settings.input.as_ref())
+        })?;
+        Some(self.resolve(&path.replace("{day}", &format!("{:02}", day))))
+    }
+
+    /// The input the config gives for part two of a day, if its parts have
+    /// their own. Only a day's own settings can give one.
+    pub fn part_two_input(&self, day: u32) -> Option<PathBuf> {
+        let path = self
+            .day(day)
+            .filter_map(|settings| settings.part_two_input.as_ref())
+            .last()?;
+        Some(self.resolve(path))
+    }
+
+    /// The parameters the config gives for a day, as `--param` names and
+    /// values.
+    pub fn params(&self, day: u32) -> Vec<(String, String)> {
+        let mut params = BTreeMap::new();
+        for settings in self.day(day) {
+            for (name, value) in settings.params.iter() {
+                params.insert(name.clone(), value_to_string(value));
+            }
+        }
+        params.into_iter().collect()
+    }
+
+    /// Fills in the output and benchmark options that were left at their
+    /// defaults on the command line. `matches` are the ones the options were
+    /// parsed from.
+    pub fn apply(&self, options: &mut RunOptions, matches: &ArgMatches) {
+        let defaulted = |id: &str| {
+            matches!(
+                matches.value_source(id),
+                None | Some(ValueSource::DefaultValue)
+            )
+        };
+
+        for settings in self.layers.iter() {
+            if let Some(format) = settings.format
+                && defaulted("format")
+            {
+                options.format = format;
+            }
+
+            let bench = &settings.bench;
+            if let Some(warmup) = bench.warmup
+                && defaulted("warmup")
+            {
+                options.bench.warmup = warmup;
+            }
+            if let Some(threshold) = bench.regression_threshold
+                && defaulted("regression_threshold")
+            {
+                options.bench.regression_threshold = threshold;
+            }
+            if let Some(baseline) = &bench.baseline
+                && defaulted("baseline")
+            {
+                options.bench.baseline = Some(self.resolve(baseline));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"
+profile = "real"
+input = "rust/day{day}/input/input.txt"
+format = "json"
+
+[bench]
+warmup = 3
+
+[day.8]
+params = { pairs = 1000 }
+
+[profiles.real]
+
+[profiles.example]
+input = "rust/day{day}/input/example.txt"
+format = "text"
+
+[profiles.example.day.08]
+params = { pairs = 10 }
+
+[profiles.example.day.3]
+input = "elsewhere/banks.txt"
+
+[profiles.example.day.1]
+params = { dial_size = [100, 10] }
+
+[profiles.example.day.11]
+part_two_input = "rust/day11/input/example2.txt"
+"#;
+
+    fn load(profile: Option<&str>) -> Result<Config, Error> {
+        let dir = env::temp_dir().join(format!("aoc-config-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("{}.toml", profile.unwrap_or("default")));
+        fs::write(&path, EXAMPLE).unwrap();
+        Config::from_file(&path, profile).map(|config| Config {
+            dir: PathBuf::from("/repo"),
+            ..config
+        })
+    }
+
+    #[test]
+    fn default_profile() {
+        let config = load(None).unwrap();
+        assert_eq!(
+            config.input(8),
+            Some(PathBuf::from("/repo/rust/day08/input/input.txt"))
+        );
+        assert_eq!(
+            config.params(8),
+            vec![(String::from("pairs"), String::from("1000"))]
+        );
+        assert!(config.params(1).is_empty());
+    }
+
+    #[test]
+    fn profile_overrides() {
+        let config = load(Some("example")).unwrap();
+        assert_eq!(
+            config.input(8),
+            Some(PathBuf::from("/repo/rust/day08/input/example.txt"))
+        );
+        assert_eq!(
+            config.input(3),
+            Some(PathBuf::from("/repo/elsewhere/banks.txt"))
+        );
+        assert_eq!(
+            config.params(8),
+            vec![(String::from("pairs"), String::from("10"))]
+        );
+        assert_eq!(config.part_two_input(8), None);
+        assert_eq!(
+            config.part_two_input(11),
+            Some(PathBuf::from("/repo/rust/day11/input/example2.txt"))
+        );
+        // lists come out the way --param takes them
+        assert_eq!(
+            config.params(1),
+            vec![(String::from("dial_size"), String::from("100,10"))]
+        );
+    }
+
+    #[test]
+    fn unknown_profile() {
+        let err = load(Some("stress")).unwrap_err();
+        match &err {
+            Error::Config { key, problem, .. } => {
+                assert_eq!(key.as_deref(), Some("profile"));
+                assert_eq!(problem, "no profile stress, only example, real");
+            }
+            other => panic!("expected a config error, got {:?}", other),
+        }
+        assert!(err.to_string().starts_with("Bad profile in "));
+    }
+
+    #[test]
+    fn bad_day_key() {
+        let dir = env::temp_dir().join(format!("aoc-config-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("bad-day.toml");
+        fs::write(&path, "[profiles.example.day.13]\ninput = \"x.txt\"\n").unwrap();
+
+        // a profile's days are only looked at when it's chosen
+        assert!(Config::from_file(&path, None).is_ok());
+        match Config::from_file(&path, Some("example")) {
+            Err(Error::Config { key, problem, .. }) => {
+                assert_eq!(key.as_deref(), Some("profiles.example.day.13"));
+                assert_eq!(problem, "13 is not a day from 1 to 12");
+            }
+            other => panic!("expected a config error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn command_line_wins() {
+        use clap::{CommandFactory, FromArgMatches, Parser};
+
+        #[derive(Parser)]
+        struct Cli {
+            #[command(flatten)]
+            options: RunOptions,
+        }
+
+        let config = load(Some("example")).unwrap();
+        for (args, format) in [
+            (vec!["aoc"], Format::Text),
+            (vec!["aoc", "--format", "json"], Format::Json),
+        ] {
+            let matches = Cli::command().get_matches_from(args);
+            let mut options = Cli::from_arg_matches(&matches).unwrap().options;
+            config.apply(&mut options, &matches);
+            assert_eq!(options.format, format);
+            assert_eq!(options.bench.warmup, 3);
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use std::error;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use serde::{Serialize, Serializer};
+
+/// A problem with the puzzle input, pinned to the line and column where it
+/// was found so it can be shown like a compiler diagnostic.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ParseError {
+    file: Option<PathBuf>,
+    line: usize,
+    column: usize,
+    source_line: String,
+    found: String,
+    expected: String,
+}
+
+impl ParseError {
+    /// Builds an error for `token`, which must be a slice of `input`. An empty
+    /// token marks the position where something was missing.
+    pub fn at(input: &str, token: &str, expected: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset + token.len() <= input.len())
+            .expect("token must be a slice of the input");
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |idx| idx + 1);
+        let source_line = input[line_start..]
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .to_string();
+
+        ParseError {
+            file: None,
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            source_line,
+            found: token.lines().next().unwrap_or_default().to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Records which file the input came from.
+    pub fn with_file(mut self, file: &Path) -> Self {
+        self.file = Some(file.to_path_buf());
+        self
+    }
+
+    /// Puts the error on line `line`, for input parsed a line at a time.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let found = if self.found.is_empty() {
+            String::from("end of line")
+        } else {
+            format!("`{}`", self.found)
+        };
+        let file = self
+            .file
+            .as_ref()
+            .map_or(String::from("<input>"), |file| file.display().to_string());
+        let gutter = " ".repeat(self.line.to_string().len());
+        let underline = "^".repeat(self.found.chars().count().max(1));
+
+        writeln!(f, "error: expected {}, found {}", self.expected, found)?;
+        writeln!(f, "{}--> {}:{}:{}", gutter, file, self.line, self.column)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(self.column - 1),
+            underline
+        )
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Parses `token`, a slice of `input`, reporting where it was on failure.
+pub fn parse_token<T: FromStr>(input: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(input, token, expected))
+}
+
+/// A puzzle parameter that doesn't parse, or doesn't make sense for the
+/// input it's solving.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ParamError {
+    name: String,
+    problem: String,
+}
+
+impl ParamError {
+    pub fn new(name: &str, problem: impl Into<String>) -> Self {
+        ParamError {
+            name: String::from(name),
+            problem: problem.into(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn problem(&self) -> &str {
+        &self.problem
+    }
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid parameter {}: {}", self.name, self.problem)
+    }
+}
+
+impl error::Error for ParamError {}
+
+/// Everything that can go wrong running a solution.
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Error {
+    Read {
+        path: PathBuf,
+        #[serde(rename = "message", serialize_with = "serialize_display")]
+        source: io::Error,
+    },
+    Write {
+        path: PathBuf,
+        #[serde(rename = "message", serialize_with = "serialize_display")]
+        source: io::Error,
+    },
+    Parse(ParseError),
+    Param(ParamError),
+    Config {
+        path: PathBuf,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        key: Option<String>,
+        problem: String,
+    },
+    Batch {
+        failed: usize,
+        total: usize,
+    },
+    Regressed {
+        phases: usize,
+    },
+    Verify {
+        failed: usize,
+        errors: usize,
+    },
+    Unsupported {
+        day: u32,
+        flag: &'static str,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Read { path, source } => {
+                write!(f, "Could not read file {}: {}", path.display(), source)
+            }
+            Error::Write { path, source } => {
+                write!(f, "Could not write file {}: {}", path.display(), source)
+            }
+            Error::Parse(err) => err.fmt(f),
+            Error::Param(err) => err.fmt(f),
+            Error::Config {
+                path,
+                key: Some(key),
+                problem,
+            } => write!(f, "Bad {} in {}: {}", key, path.display(), problem),
+            Error::Config {
+                path,
+                key: None,
+                problem,
+            } => write!(f, "Bad config file {}: {}", path.display(), problem),
+            Error::Batch { failed, total } => {
+                write!(f, "{} of {} inputs failed", failed, total)
+            }
+            Error::Regressed { phases } => {
+                write!(f, "{} phases regressed against the baseline", phases)
+            }
+            Error::Verify { failed, errors } => {
+                write!(f, "{} answers wrong, {} inputs failed", failed, errors)
+            }
+            Error::Unsupported { day, flag } => write!(f, "Day {} doesn't support {}", day, flag),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Read { source, .. } | Error::Write { source, .. } => Some(source),
+            Error::Parse(err) => Some(err),
+            Error::Param(err) => Some(err),
+            Error::Config { .. }
+            | Error::Batch { .. }
+            | Error::Regressed { .. }
+            | Error::Verify { .. }
+            | Error::Unsupported { .. } => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+impl From<ParamError> for Error {
+    fn from(err: ParamError) -> Self {
+        Error::Param(err)
+    }
+}
+
+fn serialize_display<T: fmt::Display, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
@@ -0,0 +1,345 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::ParseError;
+
+/// A position in a [`Grid`], as `(row, column)`.
+pub type Pos = (usize, usize);
+
+const OFFSETS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid of cells, stored a row at a time in one flat vector.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid by calling `f` for each position, a row at a time.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|r| (0..width).map(move |c| (r, c)))
+            .map(&mut f)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid from rows of cells, padding any short rows with `fill`
+    /// to the width of the longest.
+    pub fn from_rows(rows: Vec<Vec<T>>, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        let width = rows.iter().map(Vec::len).max().unwrap_or_default();
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for mut row in rows {
+            row.resize(width, fill.clone());
+            cells.extend(row);
+        }
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses the non-blank lines of `input` as rows, turning each character
+    /// into a cell with `cell`. A character it rejects is reported as not
+    /// being `expected`, and every row must be as wide as the first.
+    pub fn parse(
+        input: &str,
+        cell: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let lines = input.lines().filter(|line| !line.trim().is_empty());
+        Grid::parse_lines(input, lines, cell, expected)
+    }
+
+    /// Like [`Grid::parse`], for rows that are only some of the lines of
+    /// `input`. Each line must be a slice of `input`.
+    pub fn parse_lines<'a>(
+        input: &'a str,
+        lines: impl IntoIterator<Item = &'a str>,
+        mut cell: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for line in lines {
+            let line = line.trim_end();
+            let mut count = 0;
+            for (i, c) in line.char_indices() {
+                let value = cell(c)
+                    .ok_or_else(|| ParseError::at(input, &line[i..(i + c.len_utf8())], expected))?;
+                cells.push(value);
+                count += 1;
+            }
+
+            let width = *width.get_or_insert(count);
+            if count != width {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("a row {} cells wide", width),
+                ));
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or_default(),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (r, c): Pos) -> Option<&T> {
+        (r < self.height && c < self.width).then(|| &self.cells[r * self.width + c])
+    }
+
+    pub fn get_mut(&mut self, (r, c): Pos) -> Option<&mut T> {
+        (r < self.height && c < self.width).then(|| &mut self.cells[r * self.width + c])
+    }
+
+    pub fn row(&self, r: usize) -> &[T] {
+        &self.cells[(r * self.width)..((r + 1) * self.width)]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|r| self.row(r))
+    }
+
+    /// The cells a row at a time, without their positions.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |r| (0..width).map(move |c| (r, c)))
+    }
+
+    /// The cells a row at a time, with their positions.
+    pub fn indexed(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    fn offsets(
+        &self,
+        (r, c): Pos,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Pos> + use<T> {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |&(d_r, d_c)| {
+            let r = r.checked_add_signed(d_r).filter(|&r| r < height)?;
+            let c = c.checked_add_signed(d_c).filter(|&c| c < width)?;
+            Some((r, c))
+        })
+    }
+
+    /// The positions above, left, right and below `pos` that are in the
+    /// grid.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + use<T> {
+        self.offsets(pos, &OFFSETS_4)
+    }
+
+    /// The positions around `pos`, diagonals included, that are in the grid.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + use<T> {
+        self.offsets(pos, &OFFSETS_8)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |(r, c)| self[(c, r)].clone())
+    }
+
+    /// Turns the grid a quarter turn clockwise.
+    pub fn rotate_right(&self) -> Self {
+        let height = self.height;
+        Grid::from_fn(height, self.width, |(r, c)| {
+            self[(height - 1 - c, r)].clone()
+        })
+    }
+
+    /// Turns the grid a quarter turn anticlockwise.
+    pub fn rotate_left(&self) -> Self {
+        let width = self.width;
+        Grid::from_fn(self.height, width, |(r, c)| {
+            self[(c, width - 1 - r)].clone()
+        })
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        let width = self.width;
+        Grid::from_fn(width, self.height, |(r, c)| {
+            self[(r, width - 1 - c)].clone()
+        })
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        let height = self.height;
+        Grid::from_fn(self.width, height, |(r, c)| {
+            self[(height - 1 - r, c)].clone()
+        })
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (r, c): Pos) -> &T {
+        assert!(
+            c < self.width,
+            "column {} outside a grid {} wide",
+            c,
+            self.width
+        );
+        &self.cells[r * self.width + c]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (r, c): Pos) -> &mut T {
+        assert!(
+            c < self.width,
+            "column {} outside a grid {} wide",
+            c,
+            self.width
+        );
+        &mut self.cells[r * self.width + c]
+    }
+}
+
+/// Writes each row on its own line, with nothing between the cells.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letters() -> Grid<char> {
+        Grid::parse("abc\ndef\n", Some, "a letter").unwrap()
+    }
+
+    #[test]
+    fn parses_rows() {
+        let grid = letters();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn ragged_rows() {
+        let err = Grid::parse("abc\nde\n", Some, "a letter").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 1));
+        assert_eq!(err.expected(), "a row 3 cells wide");
+    }
+
+    #[test]
+    fn bad_cell() {
+        let bit = |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        };
+        let err = Grid::parse("#.\n.x\n", bit, "# or .").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 2));
+        assert_eq!(err.found(), "x");
+    }
+
+    #[test]
+    fn padded_rows() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4]], 0);
+        assert_eq!(grid.row(1), &[4, 0, 0]);
+    }
+
+    #[test]
+    fn neighbors_stay_inside() {
+        let grid = letters();
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbors8((1, 2)).collect::<Vec<_>>(),
+            [(0, 1), (0, 2), (1, 1)]
+        );
+        assert_eq!(grid.neighbors8((0, 1)).count(), 5);
+    }
+
+    #[test]
+    fn turns_and_flips() {
+        let grid = letters();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed\n");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc\n");
+
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+        assert_eq!(
+            grid.rotate_right().rotate_right(),
+            grid.flip_horizontal().flip_vertical()
+        );
+    }
+}
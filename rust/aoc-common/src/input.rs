@@ -0,0 +1,94 @@
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+
+use flate2::Compression;
+use flate2::read::MultiGzDecoder;
+use flate2::write::GzEncoder;
+
+use crate::error::Error;
+use crate::verify::ANSWERS_FILE;
+
+/// Where a day's own binary looks for its input, relative to the crate.
+pub const DEFAULT_FILEPATH: &str = "./input/input.txt";
+
+/// The input path that reads from stdin instead of a file.
+pub const STDIN: &str = "-";
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Where the `aoc` runner looks for a day's input, relative to the workspace.
+pub fn default_input_path(day: u32) -> PathBuf {
+    PathBuf::from(format!("day{:02}/input/input.txt", day))
+}
+
+/// The directory holding a day's inputs and their answers, relative to the
+/// workspace.
+pub fn default_input_dir(day: u32) -> PathBuf {
+    PathBuf::from(format!("day{:02}/input", day))
+}
+
+/// Reads a puzzle input from a file, or from stdin for [`STDIN`].
+/// Gzipped input is decompressed, whatever the file is called.
+pub fn read_input(filepath: &Path) -> io::Result<String> {
+    let mut input = String::new();
+    open_input(filepath)?.read_to_string(&mut input)?;
+    Ok(input)
+}
+
+/// Opens a puzzle input to read a bit at a time, like [`read_input`] but
+/// without holding it all in memory.
+pub fn open_input(filepath: &Path) -> io::Result<Box<dyn BufRead>> {
+    let mut reader: Box<dyn BufRead> = if filepath == Path::new(STDIN) {
+        Box::new(io::stdin().lock())
+    } else {
+        Box::new(BufReader::new(File::open(filepath)?))
+    };
+
+    if reader.fill_buf()?.starts_with(&GZIP_MAGIC) {
+        reader = Box::new(BufReader::new(MultiGzDecoder::new(reader)));
+    }
+    Ok(reader)
+}
+
+/// Writes generated output to a file, or to stdout for [`STDIN`]. Files
+/// ending in `.gz` are gzipped.
+pub fn write_output(filepath: &Path, contents: &str) -> io::Result<()> {
+    if filepath == Path::new(STDIN) {
+        return io::stdout().lock().write_all(contents.as_bytes());
+    }
+
+    if filepath.extension().is_some_and(|ext| ext == "gz") {
+        let mut encoder = GzEncoder::new(File::create(filepath)?, Compression::default());
+        encoder.write_all(contents.as_bytes())?;
+        encoder.finish()?;
+        Ok(())
+    } else {
+        fs::write(filepath, contents)
+    }
+}
+
+pub(crate) fn load_input(filepath: &Path) -> Result<String, Error> {
+    read_input(filepath).map_err(|source| Error::Read {
+        path: filepath.to_path_buf(),
+        source,
+    })
+}
+
+/// Lists the inputs in a directory for batch runs, skipping hidden files,
+/// subdirectories and the answers file.
+pub fn input_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        let hidden = name.starts_with('.');
+        if !hidden && name != ANSWERS_FILE && entry.file_type()?.is_file() {
+            files.push(entry.path());
+        }
+    }
+    files.sort();
+
+    Ok(files)
+}
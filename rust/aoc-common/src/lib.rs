@@ -0,0 +1,38 @@
+//! Shared plumbing for the Advent of Code 2025 solutions: the [`Solution`]
+//! trait each day implements, argument handling, input loading, timing and
+//! answer printing.
+
+mod answer;
+mod bench;
+mod cli;
+mod config;
+mod error;
+mod grid;
+mod input;
+mod logging;
+mod params;
+mod rng;
+mod runner;
+mod solution;
+mod table;
+mod trace;
+mod verify;
+
+pub use answer::Answer;
+pub use bench::{BenchOptions, Stats, format_duration};
+pub use cli::{Format, RunOptions, main};
+pub use config::{CONFIG_FILE, Config};
+pub use error::{Error, ParamError, ParseError, parse_token};
+pub use grid::{Grid, Pos};
+pub use input::{
+    DEFAULT_FILEPATH, STDIN, default_input_dir, default_input_path, input_files, open_input,
+    read_input, write_output,
+};
+pub use logging::{LOG_ENV, Verbosity};
+pub use params::{Params, PuzzleOptions, parse_param};
+pub use rng::Rng;
+pub use runner::{Outcome, answer, print_header, run, solve, solve_stream};
+pub use solution::Solution;
+pub use table::Table;
+pub use trace::TraceOptions;
+pub use verify::{ANSWERS_FILE, Check, verify};
@@ -0,0 +1,68 @@
+use std::io::{self, IsTerminal};
+
+use clap::{ArgAction, Args};
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::EnvFilter;
+
+/// The variable that picks what gets logged, in the `tracing` filter syntax,
+/// such as `day10=trace`. A level given for everything overrides `-v`.
+pub const LOG_ENV: &str = "AOC_LOG";
+
+/// How much the solvers log to stderr.
+#[derive(Args, Clone, Copy, Debug, Default)]
+pub struct Verbosity {
+    /// Log what the solvers are doing to stderr, -vv for every step
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub verbose: u8,
+}
+
+impl Verbosity {
+    /// The most detailed level logged when `AOC_LOG` isn't set.
+    pub fn level(&self) -> LevelFilter {
+        match self.verbose {
+            0 => LevelFilter::WARN,
+            1 => LevelFilter::DEBUG,
+            _ => LevelFilter::TRACE,
+        }
+    }
+
+    /// Sends log events to stderr, keeping stdout for the answers. Does
+    /// nothing if logging is already set up.
+    pub fn init(&self) {
+        let filter = EnvFilter::builder()
+            .with_default_directive(self.level().into())
+            .with_env_var(LOG_ENV)
+            .from_env_lossy();
+
+        let _ = tracing_subscriber::fmt()
+            .with_env_filter(filter)
+            .with_writer(io::stderr)
+            .with_ansi(io::stderr().is_terminal())
+            .try_init();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Parser)]
+    struct Cli {
+        #[command(flatten)]
+        verbosity: Verbosity,
+    }
+
+    #[test]
+    fn levels() {
+        for (args, level) in [
+            (vec!["aoc"], LevelFilter::WARN),
+            (vec!["aoc", "-v"], LevelFilter::DEBUG),
+            (vec!["aoc", "-vv"], LevelFilter::TRACE),
+            (vec!["aoc", "-v", "--verbose", "-v"], LevelFilter::TRACE),
+        ] {
+            assert_eq!(Cli::parse_from(args).verbosity.level(), level);
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use clap::Args;
+
+use crate::error::ParamError;
+
+/// Named puzzle parameters, given on the command line as `--param name=value`.
+#[derive(Clone, Debug, Default)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    pub fn new(pairs: impl IntoIterator<Item = (String, String)>) -> Self {
+        Params {
+            values: pairs.into_iter().collect(),
+        }
+    }
+
+    /// Looks up a parameter, which is an error if it was given but doesn't
+    /// parse.
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<Option<T>, ParamError> {
+        self.values
+            .get(name)
+            .map(|value| parse_value(name, value))
+            .transpose()
+    }
+
+    /// Looks up a comma separated list, which is an error if any item
+    /// doesn't parse.
+    pub fn get_list<T: FromStr>(&self, name: &str) -> Result<Option<Vec<T>>, ParamError> {
+        self.values
+            .get(name)
+            .map(|value| {
+                value
+                    .split(',')
+                    .map(|item| parse_value(name, item.trim()))
+                    .collect()
+            })
+            .transpose()
+    }
+}
+
+fn parse_value<T: FromStr>(name: &str, value: &str) -> Result<T, ParamError> {
+    value
+        .parse()
+        .map_err(|_| ParamError::new(name, format!("`{}` is not a valid value", value)))
+}
+
+/// Flags for the puzzle parameters the days read, each a shorthand for a
+/// `--param`. Days ignore the ones they don't use.
+#[derive(Args, Clone, Debug, Default)]
+#[command(next_help_heading = "Puzzle parameters")]
+pub struct PuzzleOptions {
+    /// Day 1: where the dial starts, or each dial of a lock [default: 50]
+    #[arg(long, value_name = "N,...", value_delimiter = ',')]
+    pub start: Vec<u64>,
+
+    /// Day 1: how many positions the dial has, or each dial of a lock
+    /// [default: 100]
+    #[arg(
+        long,
+        value_name = "N,...",
+        value_delimiter = ',',
+        value_parser = clap::value_parser!(u64).range(1..)
+    )]
+    pub dial_size: Vec<u64>,
+
+    /// Day 1: the positions to count visits to [default: 0]
+    #[arg(long, value_name = "N,...", value_delimiter = ',')]
+    pub targets: Vec<u64>,
+
+    /// Day 3: batteries to turn on in each bank, for part one then part two
+    /// [default: 2,12]
+    #[arg(long, value_name = "N,N", value_delimiter = ',')]
+    pub groups: Vec<usize>,
+
+    /// Day 4: a roll with fewer rolls than this around it can be reached
+    /// [default: 4]
+    #[arg(long, value_name = "N")]
+    pub threshold: Option<usize>,
+
+    /// Day 8: how many of the closest pairs of junction boxes to connect
+    /// [default: 1000]
+    #[arg(long, value_name = "N")]
+    pub pairs: Option<usize>,
+
+    /// Day 11: the device paths start from [default: you, then svr]
+    #[arg(long, value_name = "DEVICE")]
+    pub from: Option<String>,
+
+    /// Day 11: the device paths end at [default: out]
+    #[arg(long, value_name = "DEVICE")]
+    pub to: Option<String>,
+
+    /// Day 11: devices every path in part two must visit [default: dac,fft]
+    #[arg(long, value_name = "DEVICE,...", value_delimiter = ',')]
+    pub via: Vec<String>,
+}
+
+impl PuzzleOptions {
+    /// The flags that were given, as `--param` names and values.
+    pub fn params(&self) -> Vec<(String, String)> {
+        let join = |values: Vec<String>| (!values.is_empty()).then(|| values.join(","));
+
+        [
+            (
+                "start",
+                join(self.start.iter().map(u64::to_string).collect()),
+            ),
+            (
+                "dial_size",
+                join(self.dial_size.iter().map(u64::to_string).collect()),
+            ),
+            (
+                "targets",
+                join(self.targets.iter().map(u64::to_string).collect()),
+            ),
+            (
+                "groups",
+                join(self.groups.iter().map(usize::to_string).collect()),
+            ),
+            ("threshold", self.threshold.map(|n| n.to_string())),
+            ("pairs", self.pairs.map(|n| n.to_string())),
+            ("from", self.from.clone()),
+            ("to", self.to.clone()),
+            ("via", join(self.via.clone())),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| Some((String::from(name), value?)))
+        .collect()
+    }
+}
+
+/// Parses a `NAME=VALUE` command line argument.
+pub fn parse_param(s: &str) -> Result<(String, String), String> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected name=value, got {}", s))?;
+    Ok((String::from(name), String::from(value)))
+}
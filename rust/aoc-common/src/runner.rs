@@ -0,0 +1,291 @@
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use serde::{Serialize, Serializer};
+use tracing::{debug, debug_span, info_span};
+
+use crate::answer::Answer;
+use crate::bench::bench;
+use crate::cli::{Format, RunOptions};
+use crate::error::{Error, ParamError, ParseError};
+use crate::input::{input_files, load_input, open_input};
+use crate::params::Params;
+use crate::solution::Solution;
+use crate::table::Table;
+use crate::trace::trace;
+
+pub fn print_header(options: &RunOptions) {
+    if options.format == Format::Text && !options.trace.csv_to_stdout() {
+        println!("Advent of Code 2025");
+    }
+}
+
+/// The formatted answers from solving a single input.
+#[derive(Debug, Serialize)]
+pub struct Outcome {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<String>,
+    #[serde(rename = "parse_us", serialize_with = "serialize_micros")]
+    pub parse: Duration,
+    #[serde(rename = "solve_us", serialize_with = "serialize_micros")]
+    pub solve: Duration,
+}
+
+/// One line of JSON output, describing a single input.
+#[derive(Serialize)]
+struct Report<'a> {
+    day: u32,
+    title: &'static str,
+    input: &'a Path,
+    #[serde(flatten)]
+    outcome: Option<&'a Outcome>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a Error>,
+}
+
+impl Report<'_> {
+    fn print<S: Solution>(input: &Path, result: &Result<Outcome, Error>) {
+        let report = Report {
+            day: S::DAY,
+            title: S::TITLE,
+            input,
+            outcome: result.as_ref().ok(),
+            error: result.as_ref().err(),
+        };
+        println!(
+            "{}",
+            serde_json::to_string(&report).expect("reports always serialize")
+        );
+    }
+}
+
+fn serialize_micros<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(duration.as_micros() as u64)
+}
+
+// parse errors from checking the parameters point into the input file
+pub(crate) fn with_file(err: Error, input_path: &Path) -> Error {
+    match err {
+        Error::Parse(err) => Error::Parse(err.with_file(input_path)),
+        err => err,
+    }
+}
+
+/// Solves the puzzle in `input_path` without printing anything, giving the
+/// answers to the parts asked for and how long parsing and solving took.
+pub fn solve<S: Solution>(
+    input_path: &Path,
+    part: Option<u8>,
+    params: &Params,
+) -> Result<Outcome, Error> {
+    let _span = info_span!("solve", day = S::DAY, input = %input_path.display()).entered();
+
+    let text = load_input(input_path)?;
+
+    let now = Instant::now();
+    let input = debug_span!("parse")
+        .in_scope(|| S::parse(&text))
+        .map_err(|err| err.with_file(input_path))?;
+    let parse = now.elapsed();
+    debug!(?parse, "parsed");
+
+    S::check(&text, &input, part, params).map_err(|err| with_file(err, input_path))?;
+
+    let now = Instant::now();
+
+    let part_one = part.is_none_or(|part| part == 1).then(|| {
+        let answer: Answer =
+            debug_span!("part_one").in_scope(|| S::part_one(&input, params).into());
+        debug!(%answer, "solved part one");
+        answer.to_string()
+    });
+    let part_two = if part.is_none_or(|part| part == 2) {
+        let answer: Option<Answer> =
+            debug_span!("part_two").in_scope(|| S::part_two(&input, params).map(Into::into));
+        if let Some(answer) = &answer {
+            debug!(%answer, "solved part two");
+        }
+        answer.map(|answer| answer.to_string())
+    } else {
+        None
+    };
+
+    Ok(Outcome {
+        part_one,
+        part_two,
+        parse,
+        solve: now.elapsed(),
+    })
+}
+
+/// Like [`solve`], but has the day read the input itself a line at a time,
+/// so no more of it is in memory than the day keeps. Parsing is timed as
+/// part of solving.
+pub fn solve_stream<S: Solution>(
+    input_path: &Path,
+    part: Option<u8>,
+    params: &Params,
+) -> Result<Outcome, Error> {
+    let _span = info_span!("stream", day = S::DAY, input = %input_path.display()).entered();
+
+    let read_error = |source| Error::Read {
+        path: input_path.to_path_buf(),
+        source,
+    };
+
+    let now = Instant::now();
+    let mut reader = open_input(input_path).map_err(read_error)?;
+    let (part_one, part_two) = S::stream(&mut reader, params)
+        .ok_or(Error::Unsupported {
+            day: S::DAY,
+            flag: "--stream",
+        })?
+        .map_err(|err| {
+            let inner = err.get_ref();
+            if let Some(parse) = inner.and_then(|inner| inner.downcast_ref::<ParseError>()) {
+                Error::Parse(parse.clone().with_file(input_path))
+            } else if let Some(param) = inner.and_then(|inner| inner.downcast_ref::<ParamError>()) {
+                Error::Param(param.clone())
+            } else {
+                read_error(err)
+            }
+        })?;
+
+    Ok(Outcome {
+        part_one: part
+            .is_none_or(|part| part == 1)
+            .then(|| part_one.to_string()),
+        part_two: part_two
+            .filter(|_| part.is_none_or(|part| part == 2))
+            .map(|answer| answer.to_string()),
+        parse: Duration::ZERO,
+        solve: now.elapsed(),
+    })
+}
+
+/// Solves one part of the puzzle in `input_path` and returns its answer,
+/// without printing anything. Day 12 has no part two, so gives `None`.
+pub fn answer<S: Solution>(
+    input_path: &Path,
+    part: u8,
+    params: &Params,
+) -> Result<Option<String>, Error> {
+    let outcome = solve::<S>(input_path, Some(part), params)?;
+    Ok(if part == 1 {
+        outcome.part_one
+    } else {
+        outcome.part_two
+    })
+}
+
+/// Solves the puzzle in `input_path`, printing the requested parts along with
+/// the elapsed time. A directory solves every input inside it and prints a
+/// table of the results instead. With `--format json` each input is reported
+/// as a single line of JSON, including any error. With `--bench` the solution
+/// is timed over many runs instead, and with `--trace` its steps are shown.
+/// With `--stream` the day reads the input as it solves.
+pub fn run<S: Solution>(input_path: &Path, options: &RunOptions) -> Result<(), Error> {
+    if options.bench.bench.is_some() {
+        return bench::<S>(input_path, options);
+    }
+
+    if options.trace.enabled() {
+        return trace::<S>(input_path, options);
+    }
+
+    if input_path.is_dir() {
+        return run_batch::<S>(input_path, options);
+    }
+
+    let solve = if options.stream {
+        solve_stream::<S>
+    } else {
+        solve::<S>
+    };
+
+    if options.format == Format::Json {
+        let result = solve(input_path, options.part, &options.params());
+        Report::print::<S>(input_path, &result);
+        return result.map(|_| ());
+    }
+
+    println!("Day {}: {}", S::DAY, S::TITLE);
+
+    let now = Instant::now();
+
+    let outcome = solve(input_path, options.part, &options.params())?;
+
+    if let Some(part_one) = outcome.part_one {
+        println!("Part one: {}", part_one);
+    }
+    if let Some(part_two) = outcome.part_two {
+        println!("Part two: {}", part_two);
+    }
+
+    println!("Elasped time: {}ms", now.elapsed().as_millis());
+
+    Ok(())
+}
+
+fn run_batch<S: Solution>(dir: &Path, options: &RunOptions) -> Result<(), Error> {
+    let files = input_files(dir).map_err(|source| Error::Read {
+        path: dir.to_path_buf(),
+        source,
+    })?;
+
+    let params = options.params();
+    let mut table = Table::new(&["File", "Part one", "Part two", "Time"]);
+    let mut errors = vec![];
+
+    for file in files.iter() {
+        let result = if options.stream {
+            solve_stream::<S>(file, options.part, &params)
+        } else {
+            solve::<S>(file, options.part, &params)
+        };
+
+        if options.format == Format::Json {
+            Report::print::<S>(file, &result);
+        } else {
+            let name = file
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            match &result {
+                Ok(outcome) => table.push(vec![
+                    name,
+                    outcome.part_one.clone().unwrap_or_default(),
+                    outcome.part_two.clone().unwrap_or_default(),
+                    format!(
+                        "{:.3}ms",
+                        (outcome.parse + outcome.solve).as_secs_f64() * 1000.0
+                    ),
+                ]),
+                Err(_) => table.push(vec![name, String::from("error")]),
+            }
+        }
+
+        if let Err(err) = result {
+            errors.push(err);
+        }
+    }
+
+    if options.format == Format::Text {
+        println!("Day {}: {}", S::DAY, S::TITLE);
+        print!("{}", table);
+        for err in errors.iter() {
+            eprintln!("{}", err);
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::Batch {
+            failed: errors.len(),
+            total: files.len(),
+        })
+    }
+}
@@ -0,0 +1,82 @@
+use std::io::{self, BufRead};
+
+use crate::answer::Answer;
+use crate::error::{Error, ParseError};
+use crate::params::Params;
+use crate::rng::Rng;
+use crate::table::Table;
+
+/// A single day's puzzle.
+///
+/// Parsing is kept apart from solving so the two can be timed separately,
+/// and each part takes the [`Params`] given on the command line for puzzles
+/// with knobs that differ between the examples and the real input.
+pub trait Solution {
+    const DAY: u32;
+    const TITLE: &'static str;
+
+    /// The `size` for [`Solution::generate`] that matches the real puzzle
+    /// input.
+    const INPUT_SIZE: usize;
+
+    type Input;
+
+    /// Machine integers for answers that can't overflow, or [`Answer`] for
+    /// those that might.
+    type PartOne: Into<Answer>;
+    type PartTwo: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Checks the parameters the parts asked for will read against the
+    /// parsed input, so a bad value is reported before solving starts rather
+    /// than panicking partway through. `text` is what was parsed, for
+    /// pointing at the line a parameter doesn't fit. Days without
+    /// parameters have nothing to check.
+    fn check(
+        text: &str,
+        input: &Self::Input,
+        part: Option<u8>,
+        params: &Params,
+    ) -> Result<(), Error> {
+        let _ = (text, input, part, params);
+        Ok(())
+    }
+
+    fn part_one(input: &Self::Input, params: &Params) -> Self::PartOne;
+
+    /// Returns `None` for puzzles without a second part.
+    fn part_two(input: &Self::Input, params: &Params) -> Option<Self::PartTwo>;
+
+    /// Writes a random input in the format [`Solution::parse`] accepts. What
+    /// `size` counts differs from day to day, but the input grows with it.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+
+    /// A table with a row for each step of the solution, to see where a
+    /// wrong answer comes from. Days that can't show their working give
+    /// `None`.
+    fn trace(input: &Self::Input, params: &Params) -> Option<Table> {
+        let _ = (input, params);
+        None
+    }
+
+    /// Solves both parts while reading the input a line at a time, for days
+    /// whose inputs can be too big to hold in memory, or gives `None`. A
+    /// mistake in the input is an [`io::ErrorKind::InvalidData`] error
+    /// wrapping its [`ParseError`], and a bad parameter an
+    /// [`io::ErrorKind::InvalidInput`] error wrapping its [`ParamError`](crate::ParamError).
+    fn stream(
+        reader: &mut dyn BufRead,
+        params: &Params,
+    ) -> Option<io::Result<(Answer, Option<Answer>)>> {
+        let _ = (reader, params);
+        None
+    }
+
+    /// A table of how often each place was visited, for days that count
+    /// visits, or `None`.
+    fn visits(input: &Self::Input, params: &Params) -> Option<Table> {
+        let _ = (input, params);
+        None
+    }
+}
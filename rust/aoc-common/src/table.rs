@@ -0,0 +1,91 @@
+use std::fmt;
+
+/// A plain text table with columns padded to fit their widest cell.
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(headers: &[&str]) -> Self {
+        Table {
+            headers: headers.iter().map(|&header| String::from(header)).collect(),
+            rows: vec![],
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    /// The table as CSV, headers first, quoting any cell that needs it.
+    pub fn to_csv(&self) -> String {
+        let quote = |cell: &String| {
+            if cell.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", cell.replace('"', "\"\""))
+            } else {
+                cell.clone()
+            }
+        };
+
+        [&self.headers]
+            .into_iter()
+            .chain(self.rows.iter())
+            .map(|row| row.iter().map(quote).collect::<Vec<_>>().join(",") + "\n")
+            .collect()
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths = (0..self.headers.len())
+            .map(|col| {
+                self.rows
+                    .iter()
+                    .chain([&self.headers])
+                    .filter_map(|row| row.get(col))
+                    .map(|cell| cell.chars().count())
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+
+        let write_row = |f: &mut fmt::Formatter<'_>, row: &[String]| {
+            let line = row
+                .iter()
+                .zip(widths.iter())
+                .map(|(cell, &width)| format!("{:width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join("  ");
+            writeln!(f, "{}", line.trim_end())
+        };
+
+        write_row(f, &self.headers)?;
+        let rule = widths
+            .iter()
+            .map(|&width| "-".repeat(width))
+            .collect::<Vec<_>>();
+        write_row(f, &rule)?;
+        for row in self.rows.iter() {
+            write_row(f, row)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_quotes_when_needed() {
+        let mut table = Table::new(&["Name", "Note"]);
+        table.push(vec![String::from("a,b"), String::from("say \"hi\"")]);
+        table.push(vec![String::from("plain"), String::new()]);
+        assert_eq!(
+            table.to_csv(),
+            "Name,Note\n\"a,b\",\"say \"\"hi\"\"\"\nplain,\n"
+        );
+    }
+}
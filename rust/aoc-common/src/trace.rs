@@ -0,0 +1,73 @@
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+
+use crate::cli::RunOptions;
+use crate::error::Error;
+use crate::input::{STDIN, load_input, write_output};
+use crate::runner::with_file;
+use crate::solution::Solution;
+
+/// Options for showing the steps of a solution.
+#[derive(Args, Clone, Debug, Default)]
+pub struct TraceOptions {
+    /// Print a row for each step of the solution instead of the answers,
+    /// for the days that can show their working
+    #[arg(long, conflicts_with = "bench")]
+    pub trace: bool,
+
+    /// Print how often each place was visited instead of the answers, for
+    /// the days that count visits
+    #[arg(long, conflicts_with_all = ["bench", "trace"])]
+    pub visits: bool,
+
+    /// Write the steps, or the visits with --visits, as CSV to PATH, - for
+    /// stdout
+    #[arg(long, value_name = "PATH", conflicts_with = "bench")]
+    pub trace_csv: Option<PathBuf>,
+}
+
+impl TraceOptions {
+    pub fn enabled(&self) -> bool {
+        self.trace || self.visits || self.trace_csv.is_some()
+    }
+
+    /// Whether CSV is going to stdout, which then can't have anything else.
+    pub fn csv_to_stdout(&self) -> bool {
+        self.trace_csv.as_deref() == Some(Path::new(STDIN))
+    }
+}
+
+/// Prints the steps the solution takes through the input, or the visits it
+/// counted, as a table or as CSV.
+pub(crate) fn trace<S: Solution>(input_path: &Path, options: &RunOptions) -> Result<(), Error> {
+    let input = load_input(input_path)?;
+    let parsed = S::parse(&input).map_err(|err| err.with_file(input_path))?;
+    let params = options.params();
+    S::check(&input, &parsed, options.part, &params).map_err(|err| with_file(err, input_path))?;
+    let table = if options.trace.visits {
+        S::visits(&parsed, &params).ok_or(Error::Unsupported {
+            day: S::DAY,
+            flag: "--visits",
+        })?
+    } else {
+        S::trace(&parsed, &params).ok_or(Error::Unsupported {
+            day: S::DAY,
+            flag: "--trace",
+        })?
+    };
+
+    if let Some(path) = &options.trace.trace_csv {
+        write_output(path, &table.to_csv()).map_err(|source| Error::Write {
+            path: path.clone(),
+            source,
+        })?;
+    }
+
+    if (options.trace.trace || options.trace.visits) && !options.trace.csv_to_stdout() {
+        println!("Day {}: {}", S::DAY, S::TITLE);
+        print!("{}", table);
+    }
+
+    Ok(())
+}
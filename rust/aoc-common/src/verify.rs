@@ -0,0 +1,247 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::cli::{Format, RunOptions};
+use crate::error::Error;
+use crate::input::input_files;
+use crate::params::Params;
+use crate::runner::solve;
+use crate::solution::Solution;
+use crate::table::Table;
+
+/// The file next to the inputs that records their expected answers.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// The expected answers for one input, along with any puzzle parameters it
+/// needs, such as `pairs = 10` for the day 8 example.
+#[derive(Debug, Default, Deserialize)]
+struct Expected {
+    part_one: Option<toml::Value>,
+    part_two: Option<toml::Value>,
+    #[serde(default)]
+    params: BTreeMap<String, toml::Value>,
+}
+
+// answers may be written as numbers or strings, and are compared as strings
+pub(crate) fn value_to_string(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s.clone(),
+        // lists are written the way Params::get_list reads them
+        toml::Value::Array(items) => items
+            .iter()
+            .map(value_to_string)
+            .collect::<Vec<_>>()
+            .join(","),
+        other => other.to_string(),
+    }
+}
+
+/// Reads the answers recorded for the inputs in `dir`, keyed by file name.
+fn load_answers(dir: &Path) -> Result<BTreeMap<String, Expected>, Error> {
+    let path = dir.join(ANSWERS_FILE);
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+
+    let contents = fs::read_to_string(&path).map_err(|source| Error::Read {
+        path: path.clone(),
+        source,
+    })?;
+    toml::from_str(&contents).map_err(|err| Error::Read {
+        path,
+        source: io::Error::new(io::ErrorKind::InvalidData, err),
+    })
+}
+
+/// The result of checking one part's answer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum Check {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing { actual: String },
+}
+
+impl Check {
+    fn new(expected: Option<String>, actual: Option<String>) -> Option<Self> {
+        match (expected, actual) {
+            (None, None) => None,
+            (None, Some(actual)) => Some(Check::Missing { actual }),
+            (Some(expected), actual) if actual.as_ref() == Some(&expected) => Some(Check::Pass),
+            (Some(expected), actual) => Some(Check::Fail {
+                expected,
+                actual: actual.unwrap_or_else(|| String::from("no answer")),
+            }),
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Check::Pass => "pass",
+            Check::Fail { .. } => "FAIL",
+            Check::Missing { .. } => "missing",
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Verification<'a> {
+    day: u32,
+    title: &'static str,
+    input: &'a Path,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part_one: Option<Check>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part_two: Option<Check>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<Error>,
+}
+
+/// Solves each input and checks the answers against the answers file next to
+/// it. Inputs without recorded answers are reported as missing, and any wrong
+/// answer makes the whole run fail.
+pub fn verify<S: Solution>(input_path: &Path, options: &RunOptions) -> Result<(), Error> {
+    let files = if input_path.is_dir() {
+        input_files(input_path).map_err(|source| Error::Read {
+            path: input_path.to_path_buf(),
+            source,
+        })?
+    } else {
+        vec![input_path.to_path_buf()]
+    };
+
+    let mut answers = BTreeMap::<PathBuf, BTreeMap<String, Expected>>::new();
+    let mut verifications = vec![];
+
+    for file in files.iter() {
+        let dir = file.parent().unwrap_or(Path::new("")).to_path_buf();
+        if !answers.contains_key(&dir) {
+            answers.insert(dir.clone(), load_answers(&dir)?);
+        }
+        let name = file
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let expected = answers[&dir].get(&name);
+
+        // the answers file knows better than the config what its inputs need,
+        // but the command line has the last word
+        let params = Params::new(
+            options
+                .default_params
+                .iter()
+                .cloned()
+                .chain(
+                    expected
+                        .iter()
+                        .flat_map(|expected| expected.params.iter())
+                        .map(|(name, value)| (name.clone(), value_to_string(value))),
+                )
+                .chain(options.command_line_params()),
+        );
+
+        // an input with an answer for only one part, like an example given
+        // for just that part, is only solved for that part
+        let part = options.part.or(match expected {
+            Some(e) if e.part_two.is_none() && e.part_one.is_some() => Some(1),
+            Some(e) if e.part_one.is_none() && e.part_two.is_some() => Some(2),
+            _ => None,
+        });
+
+        let expected_one = expected
+            .and_then(|e| e.part_one.as_ref())
+            .map(value_to_string)
+            .filter(|_| part != Some(2));
+        let expected_two = expected
+            .and_then(|e| e.part_two.as_ref())
+            .map(value_to_string)
+            .filter(|_| part != Some(1));
+
+        let verification = match solve::<S>(file, part, &params) {
+            Ok(outcome) => Verification {
+                day: S::DAY,
+                title: S::TITLE,
+                input: file,
+                part_one: Check::new(expected_one, outcome.part_one),
+                part_two: Check::new(expected_two, outcome.part_two),
+                error: None,
+            },
+            Err(err) => Verification {
+                day: S::DAY,
+                title: S::TITLE,
+                input: file,
+                part_one: None,
+                part_two: None,
+                error: Some(err),
+            },
+        };
+        verifications.push(verification);
+    }
+
+    match options.format {
+        Format::Json => {
+            for verification in verifications.iter() {
+                println!(
+                    "{}",
+                    serde_json::to_string(verification).expect("reports always serialize")
+                );
+            }
+        }
+        Format::Text => print_verifications::<S>(&verifications),
+    }
+
+    let failed = verifications
+        .iter()
+        .flat_map(|v| [&v.part_one, &v.part_two])
+        .filter(|check| matches!(check, Some(Check::Fail { .. })))
+        .count();
+    let errors = verifications.iter().filter(|v| v.error.is_some()).count();
+
+    if failed > 0 || errors > 0 {
+        Err(Error::Verify { failed, errors })
+    } else {
+        Ok(())
+    }
+}
+
+fn print_verifications<S: Solution>(verifications: &[Verification]) {
+    println!("Day {}: {}", S::DAY, S::TITLE);
+
+    let mut table = Table::new(&["File", "Part one", "Part two"]);
+    for v in verifications.iter() {
+        let name = v
+            .input
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        if v.error.is_some() {
+            table.push(vec![name, String::from("error")]);
+        } else {
+            let label = |check: &Option<Check>| check.as_ref().map_or("", Check::label);
+            table.push(vec![
+                name,
+                String::from(label(&v.part_one)),
+                String::from(label(&v.part_two)),
+            ]);
+        }
+    }
+    print!("{}", table);
+
+    for v in verifications.iter() {
+        for (part, check) in [("one", &v.part_one), ("two", &v.part_two)] {
+            if let Some(Check::Fail { expected, actual }) = check {
+                println!();
+                println!("{} part {}:", v.input.display(), part);
+                println!("-{}", expected);
+                println!("+{}", actual);
+            }
+        }
+        if let Some(err) = &v.error {
+            eprintln!("{}", err);
+        }
+    }
+}
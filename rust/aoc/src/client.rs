@@ -0,0 +1,355 @@
+//! A small client for the Advent of Code website.
+
+use std::env;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use clap::Args;
+use ureq::Agent;
+
+/// The real site. Tests point the client at a local stand-in instead.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// The environment variable holding the session cookie.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+const USER_AGENT: &str = concat!(
+    "advent2025/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/PaulMcGuirk/advent2025)"
+);
+
+/// Options for talking to the website.
+#[derive(Args, Clone, Debug)]
+pub struct ClientOptions {
+    /// Where the puzzles live
+    #[arg(long, value_name = "URL", env = "AOC_BASE_URL", default_value = BASE_URL)]
+    pub base_url: String,
+
+    /// The session cookie from a logged-in browser. Best left in the
+    /// environment, out of the shell history
+    #[arg(long, value_name = "TOKEN", env = SESSION_ENV, hide_env_values = true)]
+    pub session: Option<String>,
+
+    /// File holding the session cookie, if there is none in the environment
+    /// [default: ~/.config/aoc/session]
+    #[arg(long, value_name = "PATH", env = "AOC_SESSION_FILE")]
+    pub session_file: Option<PathBuf>,
+
+    /// The least time between two requests to the site, in seconds
+    #[arg(long, value_name = "SECONDS", default_value_t = 5.0)]
+    pub throttle: f64,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    MissingSession {
+        path: PathBuf,
+    },
+    Request {
+        url: String,
+        source: ureq::Error,
+    },
+    Status {
+        url: String,
+        status: u16,
+        message: String,
+    },
+    Refused {
+        answer: String,
+        reason: String,
+    },
+    File(aoc_common::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingSession { path } => write!(
+                f,
+                "No session cookie: set {} or save it in {}",
+                SESSION_ENV,
+                path.display()
+            ),
+            Error::Request { url, source } => write!(f, "Request to {} failed: {}", url, source),
+            Error::Status {
+                url,
+                status,
+                message,
+            } => write!(f, "{} returned {}: {}", url, status, message),
+            Error::Refused { answer, reason } => write!(f, "Not sending {}: {}", answer, reason),
+            Error::File(err) => err.fmt(f),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Request { source, .. } => Some(source),
+            Error::File(err) => Some(err),
+            Error::MissingSession { .. } | Error::Status { .. } | Error::Refused { .. } => None,
+        }
+    }
+}
+
+impl From<aoc_common::Error> for Error {
+    fn from(err: aoc_common::Error) -> Self {
+        Error::File(err)
+    }
+}
+
+fn default_session_file() -> PathBuf {
+    let home = env::var_os("HOME").unwrap_or_default();
+    Path::new(&home).join(".config/aoc/session")
+}
+
+/// Reads the session cookie from the session file.
+fn load_session(session_file: Option<&Path>) -> Result<String, Error> {
+    let path = session_file.map_or_else(default_session_file, Path::to_path_buf);
+    match fs::read_to_string(&path) {
+        Ok(session) if !session.trim().is_empty() => Ok(String::from(session.trim())),
+        _ => Err(Error::MissingSession { path }),
+    }
+}
+
+/// Sends requests to the site with the session cookie and a User-Agent
+/// that says who we are, leaving at least the throttle interval between
+/// them.
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: Option<String>,
+    session_file: Option<PathBuf>,
+    throttle: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Client {
+    pub fn new(options: &ClientOptions) -> Self {
+        let agent = Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .new_agent();
+
+        Client {
+            agent,
+            base_url: String::from(options.base_url.trim_end_matches('/')),
+            session: options
+                .session
+                .as_deref()
+                .map(str::trim)
+                .filter(|session| !session.is_empty())
+                .map(String::from),
+            session_file: options.session_file.clone(),
+            throttle: Duration::from_secs_f64(options.throttle.max(0.0)),
+            last_request: None,
+        }
+    }
+
+    // the session is only needed once we actually talk to the site
+    fn session(&mut self) -> Result<String, Error> {
+        if self.session.is_none() {
+            self.session = Some(load_session(self.session_file.as_deref())?);
+        }
+        Ok(self.session.clone().unwrap_or_default())
+    }
+
+    fn wait_turn(&mut self) {
+        if let Some(last) = self.last_request {
+            let elapsed = last.elapsed();
+            if elapsed < self.throttle {
+                thread::sleep(self.throttle - elapsed);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    /// Gets a page, failing on anything but a success.
+    pub fn get(&mut self, path: &str) -> Result<String, Error> {
+        let url = self.url(path);
+        let cookie = format!("session={}", self.session()?);
+        self.wait_turn();
+
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", &cookie)
+            .call()
+            .map_err(|source| Error::Request {
+                url: url.clone(),
+                source,
+            })?;
+        read_response(url, response)
+    }
+
+    /// Posts a form, failing on anything but a success.
+    pub fn post(&mut self, path: &str, form: &[(&str, &str)]) -> Result<String, Error> {
+        let url = self.url(path);
+        let cookie = format!("session={}", self.session()?);
+        self.wait_turn();
+
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &cookie)
+            .send_form(form.iter().copied())
+            .map_err(|source| Error::Request {
+                url: url.clone(),
+                source,
+            })?;
+        read_response(url, response)
+    }
+}
+
+fn read_response(
+    url: String,
+    mut response: ureq::http::Response<ureq::Body>,
+) -> Result<String, Error> {
+    let status = response.status().as_u16();
+    let body = response
+        .body_mut()
+        .read_to_string()
+        .map_err(|source| Error::Request {
+            url: url.clone(),
+            source,
+        })?;
+
+    if (200..300).contains(&status) {
+        Ok(body)
+    } else {
+        let message = match status {
+            400 | 401 | 500 if body.contains("log in") => {
+                String::from("the session cookie is missing or has expired")
+            }
+            404 => String::from("the puzzle isn't unlocked yet, or doesn't exist"),
+            _ => String::from(body.lines().next().unwrap_or_default().trim()),
+        };
+        Err(Error::Status {
+            url,
+            status,
+            message,
+        })
+    }
+}
+
+/// A stand-in for the website that answers requests on a local port, so the
+/// client can be tested without a network.
+#[cfg(test)]
+pub mod stand_in {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    use super::ClientOptions;
+
+    #[derive(Clone, Debug)]
+    pub struct Request {
+        pub method: String,
+        pub path: String,
+        pub headers: Vec<(String, String)>,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(n, _)| n.eq_ignore_ascii_case(name))
+                .map(|(_, v)| v.as_str())
+        }
+    }
+
+    pub struct Server {
+        pub base_url: String,
+        pub requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    impl Server {
+        /// Serves every request with `respond`, which gives the status and
+        /// body.
+        pub fn start(respond: impl Fn(&Request) -> (u16, String) + Send + 'static) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let base_url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(vec![]));
+
+            let seen = requests.clone();
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let Ok(mut stream) = stream else { break };
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let mut parts = line.split_ascii_whitespace();
+                    let method = String::from(parts.next().unwrap_or_default());
+                    let path = String::from(parts.next().unwrap_or_default());
+
+                    let mut headers = vec![];
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        let line = line.trim_end();
+                        if line.is_empty() {
+                            break;
+                        }
+                        if let Some((name, value)) = line.split_once(':') {
+                            headers.push((String::from(name), String::from(value.trim())));
+                        }
+                    }
+
+                    let length = headers
+                        .iter()
+                        .find(|(n, _)| n.eq_ignore_ascii_case("content-length"))
+                        .map_or(0, |(_, v)| v.parse().unwrap());
+                    let mut body = vec![0; length];
+                    reader.read_exact(&mut body).unwrap();
+
+                    let request = Request {
+                        method,
+                        path,
+                        headers,
+                        body: String::from_utf8(body).unwrap(),
+                    };
+                    let (status, body) = respond(&request);
+                    seen.lock().unwrap().push(request);
+
+                    write!(
+                        stream,
+                        "HTTP/1.1 {} Stand-in\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    )
+                    .unwrap();
+                }
+            });
+
+            Server { base_url, requests }
+        }
+
+        pub fn requests(&self) -> Vec<Request> {
+            self.requests.lock().unwrap().clone()
+        }
+
+        pub fn options(&self) -> ClientOptions {
+            ClientOptions {
+                base_url: self.base_url.clone(),
+                session: None,
+                session_file: None,
+                throttle: 0.0,
+            }
+        }
+    }
+}
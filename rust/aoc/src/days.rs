@@ -0,0 +1,78 @@
+use std::path::Path;
+
+use aoc_common::{Error, Outcome, Params, Rng, RunOptions, Solution};
+
+/// A type-erased handle on one day's [`Solution`].
+pub struct Day {
+    pub day: u32,
+    pub title: &'static str,
+    pub input_size: usize,
+    pub run: fn(&Path, &RunOptions) -> Result<(), Error>,
+    pub verify: fn(&Path, &RunOptions) -> Result<(), Error>,
+    pub generate: fn(&mut Rng, usize) -> String,
+    pub answer: fn(&Path, u8, &Params) -> Result<Option<String>, Error>,
+    pub solve: fn(&Path, Option<u8>, &Params) -> Result<Outcome, Error>,
+}
+
+const fn day<S: Solution>() -> Day {
+    Day {
+        day: S::DAY,
+        title: S::TITLE,
+        input_size: S::INPUT_SIZE,
+        run: aoc_common::run::<S>,
+        verify: aoc_common::verify::<S>,
+        generate: S::generate,
+        answer: aoc_common::answer::<S>,
+        solve: aoc_common::solve::<S>,
+    }
+}
+
+pub const DAYS: [Day; 12] = [
+    day::<day01::Day01>(),
+    day::<day02::Day02>(),
+    day::<day03::Day03>(),
+    day::<day04::Day04>(),
+    day::<day05::Day05>(),
+    day::<day06::Day06>(),
+    day::<day07::Day07>(),
+    day::<day08::Day08>(),
+    day::<day09::Day09>(),
+    day::<day10::Day10>(),
+    day::<day11::Day11>(),
+    day::<day12::Day12>(),
+];
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::process;
+
+    use super::*;
+
+    #[test]
+    fn generated_inputs_solve() {
+        let dir = env::temp_dir().join(format!("aoc-days-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        // day 8 can't connect more pairs of junction boxes than the smallest
+        // input has
+        let params = Params::new([(String::from("pairs"), String::from("1"))]);
+
+        for day in DAYS.iter() {
+            for size in [0, 1, 2, 3, 5, 8] {
+                for seed in 0..5 {
+                    let input = (day.generate)(&mut Rng::new(seed), size);
+                    assert_eq!(input, (day.generate)(&mut Rng::new(seed), size));
+
+                    let path = dir.join(format!("day{:02}-{}-{}.txt", day.day, size, seed));
+                    fs::write(&path, &input).unwrap();
+                    let outcome = (day.solve)(&path, None, &params).unwrap_or_else(|err| {
+                        panic!("day {} size {} seed {}: {}", day.day, size, seed, err)
+                    });
+                    assert!(outcome.part_one.is_some(), "day {} size {}", day.day, size);
+                    assert_eq!(outcome.part_two.is_some(), day.day != 12);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use std::fs;
+use std::path::Path;
+
+use aoc_common::Error as FileError;
+
+use crate::client::{Client, Error};
+
+/// What [`fetch`] did about an input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    Cached,
+}
+
+/// Downloads the input for one day to `path`, unless there is a file there
+/// already. Inputs never change, so a file that exists is never fetched
+/// again.
+pub fn fetch(client: &mut Client, year: u32, day: u32, path: &Path) -> Result<Fetched, Error> {
+    if path.exists() {
+        return Ok(Fetched::Cached);
+    }
+
+    let input = client.get(&format!("/{}/day/{}/input", year, day))?;
+
+    // write to the side and rename, so an interrupted download never looks
+    // like a cached input
+    let write_error = |source| FileError::Write {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(write_error)?;
+    }
+    let partial = path.with_extension("part");
+    fs::write(&partial, input).map_err(write_error)?;
+    fs::rename(&partial, path).map_err(write_error)?;
+
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::path::PathBuf;
+    use std::process;
+    use std::time::{Duration, Instant};
+
+    use super::*;
+    use crate::client::stand_in::Server;
+
+    // a fresh directory for each test, holding a session file
+    fn scratch(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("session"), "abc123\n").unwrap();
+        dir
+    }
+
+    fn client(server: &Server, dir: &Path) -> Client {
+        let mut options = server.options();
+        options.session_file = Some(dir.join("session"));
+        Client::new(&options)
+    }
+
+    #[test]
+    fn downloads_input() {
+        let dir = scratch("downloads");
+        let server = Server::start(|_| (200, String::from("L68\nL30\n")));
+        let path = dir.join("day01/input/input.txt");
+
+        let fetched = fetch(&mut client(&server, &dir), 2025, 1, &path).unwrap();
+        assert_eq!(fetched, Fetched::Downloaded);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "L68\nL30\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2025/day/1/input");
+        assert!(requests[0].body.is_empty());
+        assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+        assert!(
+            requests[0]
+                .header("user-agent")
+                .unwrap()
+                .contains("advent2025")
+        );
+    }
+
+    #[test]
+    fn never_downloads_twice() {
+        let dir = scratch("cached");
+        let server = Server::start(|_| (200, String::from("1\n")));
+        let path = dir.join("input.txt");
+        fs::write(&path, "0\n").unwrap();
+
+        let mut client = client(&server, &dir);
+        assert_eq!(fetch(&mut client, 2025, 1, &path).unwrap(), Fetched::Cached);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "0\n");
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn locked_puzzle_leaves_no_file() {
+        let dir = scratch("locked");
+        let server = Server::start(|_| (404, String::from("404 Not Found")));
+        let path = dir.join("input.txt");
+
+        let err = fetch(&mut client(&server, &dir), 2025, 25, &path).unwrap_err();
+        assert!(matches!(err, Error::Status { status: 404, .. }));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn missing_session() {
+        let dir = scratch("no-session");
+        let server = Server::start(|_| (200, String::new()));
+        fs::remove_file(dir.join("session")).unwrap();
+
+        let err = fetch(&mut client(&server, &dir), 2025, 1, &dir.join("input.txt")).unwrap_err();
+        assert!(matches!(err, Error::MissingSession { .. }));
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn waits_between_requests() {
+        let dir = scratch("throttle");
+        let server = Server::start(|_| (200, String::from("1\n")));
+        let mut options = server.options();
+        options.session_file = Some(dir.join("session"));
+        options.throttle = 0.2;
+        let mut client = Client::new(&options);
+
+        let start = Instant::now();
+        fetch(&mut client, 2025, 1, &dir.join("1.txt")).unwrap();
+        fetch(&mut client, 2025, 2, &dir.join("2.txt")).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        assert_eq!(server.requests().len(), 2);
+    }
+}
@@ -0,0 +1,531 @@
+//! A single entry point for every Advent of Code 2025 solution.
+
+use std::path::{Path, PathBuf};
+use std::process;
+
+use aoc_common::{
+    Config, Error, Format, Params, PuzzleOptions, Rng, RunOptions, STDIN, Verbosity,
+    default_input_dir, default_input_path, parse_param, print_header, write_output,
+};
+use clap::{ArgGroup, ArgMatches, Args, CommandFactory, FromArgMatches, Parser, Subcommand};
+
+mod client;
+mod days;
+mod fetch;
+mod submit;
+mod summary;
+
+use client::{Client, ClientOptions};
+use days::{DAYS, Day};
+use fetch::{Fetched, fetch};
+use submit::{Hint, SUBMISSIONS_FILE, Verdict, submit};
+use summary::{Job, print_summary, run_jobs};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2025")]
+struct Cli {
+    /// The aoc.toml profile to take inputs and settings from
+    #[arg(long, global = true)]
+    profile: Option<String>,
+
+    #[command(flatten)]
+    verbosity: Verbosity,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day's puzzle, or the whole calendar
+    Run(RunArgs),
+    /// Check the answers against the answers.toml next to each input
+    Verify(VerifyArgs),
+    /// Write a random puzzle input for one day
+    Gen(GenArgs),
+    /// Download puzzle inputs from the website, skipping any already saved
+    Fetch(FetchArgs),
+    /// Solve one part and send the answer to the website
+    Submit(SubmitArgs),
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("days").required(true).args(["day", "all"])))]
+struct RunArgs {
+    /// The day to solve
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=12))]
+    day: Option<u32>,
+
+    /// Solve every day in turn
+    #[arg(long, conflicts_with = "input")]
+    all: bool,
+
+    /// Path to the puzzle input, - for stdin, or a directory of inputs
+    /// [default: dayNN/input/input.txt]
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Solve the days on N threads and print a summary table of them all
+    #[arg(
+        long,
+        value_name = "N",
+        value_parser = clap::value_parser!(u32).range(1..),
+        requires = "all",
+        conflicts_with_all = ["bench", "trace", "visits", "trace_csv"]
+    )]
+    jobs: Option<u32>,
+
+    #[command(flatten)]
+    options: RunOptions,
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("days").required(true).args(["day", "all"])))]
+struct VerifyArgs {
+    /// The day to check
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=12))]
+    day: Option<u32>,
+
+    /// Check every day in turn
+    #[arg(long, conflicts_with = "input")]
+    all: bool,
+
+    /// Path to an input, or a directory of inputs [default: dayNN/input]
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    #[command(flatten)]
+    options: RunOptions,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    /// The day to write an input for
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=12))]
+    day: u32,
+
+    /// Seed for the random numbers; the same seed always gives the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// How big an input to write, counted in that day's own units
+    /// [default: the size of the real input]
+    #[arg(long)]
+    size: Option<usize>,
+
+    /// Where to write the input, - for stdout. Names ending in .gz are gzipped
+    #[arg(long, default_value = STDIN)]
+    output: PathBuf,
+
+    #[command(flatten)]
+    counts: CountArgs,
+}
+
+/// Settings for writing day 1 turns that hit given counts.
+#[derive(Args)]
+#[command(next_help_heading = "Day 1 counts")]
+struct CountArgs {
+    /// Write turns that land on zero exactly N times, checked by solving them
+    #[arg(long, value_name = "N", requires = "passed")]
+    landed: Option<u64>,
+
+    /// Clicks that point at zero without the turn stopping there
+    #[arg(long, value_name = "N", requires = "landed")]
+    passed: Option<u64>,
+
+    /// The most clicks in one turn
+    #[arg(
+        long,
+        value_name = "N",
+        default_value_t = 999,
+        value_parser = clap::value_parser!(u32).range(1..),
+        requires = "landed"
+    )]
+    max_distance: u32,
+
+    /// Where the dial starts
+    #[arg(long, value_name = "N", default_value_t = day01::START, requires = "landed")]
+    start: u32,
+
+    /// How many positions the dial has
+    #[arg(
+        long,
+        value_name = "N",
+        default_value_t = day01::DIAL_SIZE,
+        value_parser = clap::value_parser!(u32).range(1..),
+        requires = "landed"
+    )]
+    dial_size: u32,
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("days").required(true).args(["day", "all"])))]
+struct FetchArgs {
+    /// The year of the puzzles
+    #[arg(long, default_value_t = 2025)]
+    year: u32,
+
+    /// The day to download
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=12))]
+    day: Option<u32>,
+
+    /// Download every day that isn't saved yet
+    #[arg(long)]
+    all: bool,
+
+    #[command(flatten)]
+    client: ClientOptions,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// The year of the puzzles
+    #[arg(long, default_value_t = 2025)]
+    year: u32,
+
+    /// The day to submit
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=12))]
+    day: u32,
+
+    /// The part to submit
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Path to the puzzle input [default: dayNN/input/input.txt]
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Puzzle parameter as NAME=VALUE, may be repeated
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+
+    /// Where to keep the log of every answer sent
+    #[arg(long, default_value = SUBMISSIONS_FILE)]
+    log: PathBuf,
+
+    #[command(flatten)]
+    client: ClientOptions,
+
+    #[command(flatten)]
+    puzzle: PuzzleOptions,
+}
+
+type Action = fn(&Day) -> fn(&Path, &RunOptions) -> Result<(), Error>;
+
+// the inputs for a day when none is given, with the part to solve in each
+type Inputs = fn(&Config, u32, Option<u8>) -> Vec<(Option<u8>, PathBuf)>;
+
+// where run reads a day's input from when none is given, which for part two
+// can be an input of its own
+fn run_input(config: &Config, day: u32, part: Option<u8>) -> PathBuf {
+    match (part, config.part_two_input(day)) {
+        (Some(2), Some(input)) => input,
+        _ => config.input(day).unwrap_or_else(|| default_input_path(day)),
+    }
+}
+
+// the parts to run and the input for each: one input for both parts, unless
+// part two has its own
+fn run_inputs(config: &Config, day: u32, part: Option<u8>) -> Vec<(Option<u8>, PathBuf)> {
+    let parts = if part.is_none() && config.part_two_input(day).is_some() {
+        vec![Some(1), Some(2)]
+    } else {
+        vec![part]
+    };
+    parts
+        .into_iter()
+        .map(|part| (part, run_input(config, day, part)))
+        .collect()
+}
+
+// verify checks every input in the directory the config's input is in, not
+// just that one file
+fn verify_input(config: &Config, day: u32) -> PathBuf {
+    config
+        .input(day)
+        .and_then(|input| input.parent().map(Path::to_path_buf))
+        .unwrap_or_else(|| default_input_dir(day))
+}
+
+fn for_each_day(
+    day: Option<u32>,
+    input: Option<PathBuf>,
+    default_inputs: Inputs,
+    mut options: RunOptions,
+    config: (&Config, &ArgMatches),
+    action: Action,
+) {
+    let days = match day {
+        Some(day) => &DAYS[(day as usize - 1)..(day as usize)],
+        None => &DAYS[..],
+    };
+
+    let (config, matches) = config;
+    config.apply(&mut options, matches);
+    print_header(&options);
+
+    let mut failed = false;
+    for (i, day) in days.iter().enumerate() {
+        if i > 0 && options.format == Format::Text {
+            println!();
+        }
+
+        let inputs = match &input {
+            Some(input) => vec![(options.part, input.clone())],
+            None => default_inputs(config, day.day, options.part),
+        };
+        options.default_params = config.params(day.day);
+        for (part, input) in inputs {
+            let options = RunOptions {
+                part,
+                ..options.clone()
+            };
+            if let Err(err) = action(day)(&input, &options) {
+                eprintln!("{}", err);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn run_all(threads: usize, mut options: RunOptions, config: (&Config, &ArgMatches)) {
+    let (config, matches) = config;
+    config.apply(&mut options, matches);
+
+    let jobs = DAYS
+        .iter()
+        .flat_map(|day| {
+            options.default_params = config.params(day.day);
+            let params = options.params();
+            run_inputs(config, day.day, options.part)
+                .into_iter()
+                .map(move |(part, input)| Job {
+                    day,
+                    input,
+                    part,
+                    params: params.clone(),
+                })
+        })
+        .collect::<Vec<_>>();
+
+    let (rows, wall) = run_jobs(&jobs, threads);
+    if print_summary(&rows, wall, options.format) {
+        process::exit(1);
+    }
+}
+
+fn generate(args: GenArgs) {
+    let day = &DAYS[args.day as usize - 1];
+    let size = args.size.unwrap_or(day.input_size);
+    let mut rng = Rng::new(args.seed);
+
+    let input = match (args.counts.landed, args.counts.passed) {
+        (Some(landed), Some(passed)) => {
+            if args.day != 1 {
+                eprintln!("--landed and --passed are only for day 1");
+                process::exit(1);
+            }
+            let counts = &args.counts;
+            let turns = day01::generate_counts(
+                &mut rng,
+                counts.start,
+                counts.dial_size,
+                day01::Counts { landed, passed },
+                size,
+                counts.max_distance,
+            )
+            .unwrap_or_else(|err| {
+                eprintln!("{}", err);
+                process::exit(1);
+            });
+            day01::format_turns(&turns)
+        }
+        _ => (day.generate)(&mut rng, size),
+    };
+
+    if let Err(source) = write_output(&args.output, &input) {
+        let err = Error::Write {
+            path: args.output,
+            source,
+        };
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+fn download(args: FetchArgs, config: &Config) {
+    let days = match args.day {
+        Some(day) => day..=day,
+        None => 1..=12,
+    };
+
+    let mut client = Client::new(&args.client);
+    let mut failed = false;
+    for day in days {
+        // save it where run will look for it
+        let path = run_input(config, day, None);
+        match fetch(&mut client, args.year, day, &path) {
+            Ok(Fetched::Downloaded) => println!("Day {}: saved {}", day, path.display()),
+            Ok(Fetched::Cached) => println!("Day {}: already have {}", day, path.display()),
+            Err(err) => {
+                eprintln!("Day {}: {}", day, err);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn send(args: SubmitArgs, config: &Config) {
+    let day = &DAYS[args.day as usize - 1];
+    let input = args
+        .input
+        .unwrap_or_else(|| run_input(config, args.day, Some(args.part)));
+    let params = Params::new(
+        config
+            .params(args.day)
+            .into_iter()
+            .chain(args.params)
+            .chain(args.puzzle.params()),
+    );
+
+    let answer = match (day.answer)(&input, args.part, &params) {
+        Ok(Some(answer)) => answer,
+        Ok(None) => {
+            eprintln!("Day {} has no answer for part {}", args.day, args.part);
+            process::exit(1);
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    };
+
+    let mut client = Client::new(&args.client);
+    match submit(
+        &mut client,
+        &args.log,
+        args.year,
+        args.day,
+        args.part,
+        &answer,
+    ) {
+        Ok(Verdict::Correct) => println!("{} is right", answer),
+        Ok(Verdict::Wrong { hint }) => {
+            let hint = match hint {
+                Some(Hint::TooHigh) => " (too high)",
+                Some(Hint::TooLow) => " (too low)",
+                None => "",
+            };
+            println!("{} is wrong{}", answer, hint);
+            process::exit(1);
+        }
+        Ok(Verdict::RateLimited { wait_s }) => {
+            println!("Answered too recently, try again in {}s", wait_s);
+            process::exit(1);
+        }
+        Ok(Verdict::WrongLevel) => {
+            println!("Part {} is already solved, or not open yet", args.part);
+            process::exit(1);
+        }
+        Ok(Verdict::Unknown { message }) => {
+            println!("Unexpected reply: {}", message);
+            process::exit(1);
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    }
+}
+
+fn main() {
+    let matches = Cli::command().get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|err| err.exit());
+    cli.verbosity.init();
+
+    let config = Config::load(cli.profile.as_deref()).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        process::exit(1);
+    });
+    let (_, sub_matches) = matches.subcommand().expect("a command is required");
+
+    match cli.command {
+        Command::Run(RunArgs {
+            jobs: Some(jobs),
+            options,
+            ..
+        }) => run_all(jobs as usize, options, (&config, sub_matches)),
+        Command::Run(args) => for_each_day(
+            args.day,
+            args.input,
+            run_inputs,
+            args.options,
+            (&config, sub_matches),
+            |day| day.run,
+        ),
+        Command::Verify(args) => for_each_day(
+            args.day,
+            args.input,
+            |config, day, part| vec![(part, verify_input(config, day))],
+            args.options,
+            (&config, sub_matches),
+            |day| day.verify,
+        ),
+        Command::Gen(args) => generate(args),
+        Command::Fetch(args) => download(args, &config),
+        Command::Submit(args) => send(args, &config),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::process;
+
+    use aoc_common::{ANSWERS_FILE, CONFIG_FILE};
+
+    use super::*;
+
+    #[test]
+    fn verify_checks_the_whole_input_dir() {
+        let dir = env::temp_dir().join(format!("aoc-main-{}", process::id()));
+        let inputs = dir.join("day01/input");
+        fs::create_dir_all(&inputs).unwrap();
+        fs::write(
+            dir.join(CONFIG_FILE),
+            "input = \"day{day}/input/input.txt\"\n",
+        )
+        .unwrap();
+        let turns = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+        fs::write(inputs.join("input.txt"), turns).unwrap();
+        fs::write(inputs.join("example.txt"), turns).unwrap();
+        fs::write(
+            inputs.join(ANSWERS_FILE),
+            "[\"input.txt\"]\npart_one = 3\n\n[\"example.txt\"]\npart_one = 4\n",
+        )
+        .unwrap();
+
+        let config = Config::from_file(&dir.join(CONFIG_FILE), None).unwrap();
+        assert_eq!(run_input(&config, 1, None), inputs.join("input.txt"));
+        assert_eq!(verify_input(&config, 1), inputs);
+
+        // the wrong answer for example.txt fails the run
+        let result = (DAYS[0].verify)(&verify_input(&config, 1), &RunOptions::default());
+        assert!(matches!(
+            result,
+            Err(Error::Verify {
+                failed: 1,
+                errors: 0
+            })
+        ));
+    }
+}
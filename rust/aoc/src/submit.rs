@@ -0,0 +1,444 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use aoc_common::Error as FileError;
+use serde::{Deserialize, Serialize};
+
+use crate::client::{Client, Error};
+
+/// The log of every answer sent, one JSON object per line.
+pub const SUBMISSIONS_FILE: &str = "submissions.jsonl";
+
+/// Which way a wrong answer was off, when the site says.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// What the site made of an answer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "verdict", rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Wrong {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        hint: Option<Hint>,
+    },
+    RateLimited {
+        wait_s: u64,
+    },
+    /// The part was solved already, or isn't open yet.
+    WrongLevel,
+    /// A reply we don't recognise, kept as its text.
+    Unknown {
+        message: String,
+    },
+}
+
+/// One line of the submissions log.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub answer: String,
+    #[serde(flatten)]
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch.
+    pub at: u64,
+}
+
+// the text of the reply's <article>, which is all that matters on the page
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// reads a wait like "1m 23s" from "You have 1m 23s left to wait."
+fn wait_seconds(text: &str) -> Option<u64> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    text[start..end]
+        .split_whitespace()
+        .map(|part| {
+            let units = [('h', 3600), ('m', 60), ('s', 1)];
+            let (n, scale) = units
+                .iter()
+                .find_map(|&(unit, scale)| Some((part.strip_suffix(unit)?, scale)))?;
+            n.parse::<u64>().ok().map(|n| n * scale)
+        })
+        .sum()
+}
+
+/// Works out the verdict from the page the site sends back.
+pub fn parse_verdict(html: &str) -> Verdict {
+    let text = article_text(html);
+
+    if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("That's not the right answer") {
+        let hint = if text.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        Verdict::Wrong { hint }
+    } else if text.contains("You gave an answer too recently") {
+        Verdict::RateLimited {
+            wait_s: wait_seconds(&text).unwrap_or(60),
+        }
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unknown { message: text }
+    }
+}
+
+/// Reads the submissions log, which is empty until the first submission.
+pub fn load_log(path: &Path) -> Result<Vec<Submission>, FileError> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(source) => {
+            return Err(FileError::Read {
+                path: path.to_path_buf(),
+                source,
+            });
+        }
+    };
+
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line).map_err(|err| FileError::Read {
+                path: path.to_path_buf(),
+                source: io::Error::new(io::ErrorKind::InvalidData, err),
+            })
+        })
+        .collect()
+}
+
+fn append_log(path: &Path, submission: &Submission) -> Result<(), FileError> {
+    let line = serde_json::to_string(submission).expect("submissions always serialize");
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| writeln!(file, "{}", line))
+        .map_err(|source| FileError::Write {
+            path: path.to_path_buf(),
+            source,
+        })
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs())
+}
+
+/// Checks an answer against what the log already says, giving the reason
+/// not to send it if there is one.
+pub fn known_outcome(
+    log: &[Submission],
+    year: u32,
+    day: u32,
+    part: u8,
+    answer: &str,
+    now: u64,
+) -> Option<String> {
+    // the site rate limits the whole account, not just one puzzle or year
+    if let Some(last) = log.last()
+        && let Verdict::RateLimited { wait_s } = last.verdict
+        && last.at + wait_s > now
+    {
+        return Some(format!(
+            "the site asked us to wait another {}s",
+            last.at + wait_s - now
+        ));
+    }
+
+    let value = answer.parse::<i128>().ok();
+    for s in log
+        .iter()
+        .filter(|s| s.year == year && s.day == day && s.part == part)
+    {
+        let previous = s.answer.parse::<i128>().ok();
+        match &s.verdict {
+            Verdict::Correct if s.answer == answer => {
+                return Some(String::from("it was already accepted"));
+            }
+            Verdict::Correct => {
+                return Some(format!("the part was already solved with {}", s.answer));
+            }
+            Verdict::Wrong { .. } if s.answer == answer => {
+                return Some(String::from("it was already rejected"));
+            }
+            Verdict::Wrong { hint: Some(hint) } => {
+                // a bound rules out everything past it as well
+                if let (Some(value), Some(previous)) = (value, previous) {
+                    match hint {
+                        Hint::TooHigh if value >= previous => {
+                            return Some(format!("{} was already too high", s.answer));
+                        }
+                        Hint::TooLow if value <= previous => {
+                            return Some(format!("{} was already too low", s.answer));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Sends an answer to the site and records the verdict in the log at
+/// `log_path`, unless the log shows it is already known to be wrong or the
+/// part is already solved.
+pub fn submit(
+    client: &mut Client,
+    log_path: &Path,
+    year: u32,
+    day: u32,
+    part: u8,
+    answer: &str,
+) -> Result<Verdict, Error> {
+    let log = load_log(log_path)?;
+    if let Some(reason) = known_outcome(&log, year, day, part, answer, now()) {
+        return Err(Error::Refused {
+            answer: String::from(answer),
+            reason,
+        });
+    }
+
+    let level = part.to_string();
+    let page = client.post(
+        &format!("/{}/day/{}/answer", year, day),
+        &[("level", &level), ("answer", answer)],
+    )?;
+    let verdict = parse_verdict(&page);
+
+    append_log(
+        log_path,
+        &Submission {
+            year,
+            day,
+            part,
+            answer: String::from(answer),
+            verdict: verdict.clone(),
+            at: now(),
+        },
+    )?;
+
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::path::PathBuf;
+    use std::process;
+
+    use super::*;
+    use crate::client::stand_in::Server;
+
+    fn page(text: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            text
+        )
+    }
+
+    fn scratch(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-submit-{}-{}", process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn client(server: &Server) -> Client {
+        let mut options = server.options();
+        options.session = Some(String::from("abc123"));
+        Client::new(&options)
+    }
+
+    fn logged(answer: &str, verdict: Verdict, at: u64) -> Submission {
+        Submission {
+            year: 2025,
+            day: 1,
+            part: 1,
+            answer: String::from(answer),
+            verdict,
+            at,
+        }
+    }
+
+    #[test]
+    fn verdicts() {
+        assert_eq!(
+            parse_verdict(&page(
+                "That's the right answer! You are <em>one gold star</em> closer."
+            )),
+            Verdict::Correct
+        );
+        assert_eq!(
+            parse_verdict(&page(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            )),
+            Verdict::Wrong {
+                hint: Some(Hint::TooHigh)
+            }
+        );
+        assert_eq!(
+            parse_verdict(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Verdict::Wrong {
+                hint: Some(Hint::TooLow)
+            }
+        );
+        assert_eq!(
+            parse_verdict(&page("That's not the right answer.")),
+            Verdict::Wrong { hint: None }
+        );
+        assert_eq!(
+            parse_verdict(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again. You have 1m 23s left to wait."
+            )),
+            Verdict::RateLimited { wait_s: 83 }
+        );
+        assert_eq!(
+            parse_verdict(&page(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            )),
+            Verdict::WrongLevel
+        );
+        assert_eq!(
+            parse_verdict("<p>Something <b>else</b></p>"),
+            Verdict::Unknown {
+                message: String::from("Something else")
+            }
+        );
+    }
+
+    #[test]
+    fn posts_and_logs() {
+        let dir = scratch("posts");
+        let log = dir.join(SUBMISSIONS_FILE);
+        let server = Server::start(|_| {
+            (
+                200,
+                page("That's not the right answer; your answer is too low."),
+            )
+        });
+
+        let verdict = submit(&mut client(&server), &log, 2025, 3, 2, "357").unwrap();
+        assert_eq!(
+            verdict,
+            Verdict::Wrong {
+                hint: Some(Hint::TooLow)
+            }
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2025/day/3/answer");
+        assert_eq!(requests[0].body, "level=2&answer=357");
+
+        let entries = load_log(&log).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!((entries[0].day, entries[0].part), (3, 2));
+        assert_eq!(entries[0].verdict, verdict);
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let dir = scratch("refuses");
+        let log = dir.join(SUBMISSIONS_FILE);
+        let server = Server::start(|_| (200, page("That's the right answer!")));
+        let mut client = client(&server);
+
+        for entry in [
+            logged("1", Verdict::Wrong { hint: None }, 0),
+            logged(
+                "100",
+                Verdict::Wrong {
+                    hint: Some(Hint::TooHigh),
+                },
+                0,
+            ),
+            logged(
+                "10",
+                Verdict::Wrong {
+                    hint: Some(Hint::TooLow),
+                },
+                0,
+            ),
+        ] {
+            append_log(&log, &entry).unwrap();
+        }
+
+        for answer in ["1", "100", "250", "10", "3"] {
+            let err = submit(&mut client, &log, 2025, 1, 1, answer).unwrap_err();
+            assert!(matches!(err, Error::Refused { .. }), "{}", answer);
+        }
+        assert!(server.requests().is_empty());
+
+        assert_eq!(
+            submit(&mut client, &log, 2025, 1, 1, "50").unwrap(),
+            Verdict::Correct
+        );
+        let err = submit(&mut client, &log, 2025, 1, 1, "60").unwrap_err();
+        assert!(matches!(err, Error::Refused { .. }));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn waits_out_rate_limits() {
+        let log = [logged("5", Verdict::RateLimited { wait_s: 60 }, 1000)];
+        assert_eq!(
+            known_outcome(&log, 2025, 2, 1, "7", 1030),
+            Some(String::from("the site asked us to wait another 30s"))
+        );
+        assert_eq!(known_outcome(&log, 2025, 2, 1, "7", 1060), None);
+        assert_eq!(
+            known_outcome(&log, 2024, 2, 1, "7", 1030),
+            Some(String::from("the site asked us to wait another 30s"))
+        );
+    }
+
+    #[test]
+    fn other_parts_are_separate() {
+        let log = [logged("5", Verdict::Correct, 0)];
+        assert!(known_outcome(&log, 2025, 1, 1, "5", 0).is_some());
+        assert_eq!(known_outcome(&log, 2025, 1, 2, "5", 0), None);
+        assert_eq!(known_outcome(&log, 2025, 2, 1, "5", 0), None);
+    }
+}
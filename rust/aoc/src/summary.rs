@@ -0,0 +1,239 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_common::{Error, Format, Outcome, Params, Table, format_duration};
+use serde::Serialize;
+
+use crate::days::Day;
+
+/// One day to solve, with everything it needs.
+pub struct Job {
+    pub day: &'static Day,
+    pub input: PathBuf,
+    pub part: Option<u8>,
+    pub params: Params,
+}
+
+/// Why a day has no answers.
+#[derive(Debug, Serialize)]
+#[serde(tag = "failure", rename_all = "snake_case")]
+pub enum Failure {
+    Error { error: Error },
+    Panic { message: String },
+}
+
+/// The result of one day in the summary.
+#[derive(Debug)]
+pub struct Row {
+    pub day: u32,
+    /// The part solved, for a day whose parts have their own inputs.
+    pub part: Option<u8>,
+    pub title: &'static str,
+    pub result: Result<Outcome, Failure>,
+}
+
+impl Row {
+    fn time(&self) -> Duration {
+        self.result
+            .as_ref()
+            .map_or(Duration::ZERO, |outcome| outcome.parse + outcome.solve)
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        String::from(*message)
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+fn run_job(job: &Job) -> Row {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        (job.day.solve)(&job.input, job.part, &job.params)
+    }));
+
+    Row {
+        day: job.day.day,
+        part: job.part,
+        title: job.day.title,
+        result: match result {
+            Ok(Ok(outcome)) => Ok(outcome),
+            Ok(Err(error)) => Err(Failure::Error { error }),
+            Err(payload) => Err(Failure::Panic {
+                message: panic_message(payload.as_ref()),
+            }),
+        },
+    }
+}
+
+/// Solves every job on `threads` threads, catching any that panic, and
+/// returns the results sorted by day along with the wall time taken.
+pub fn run_jobs(jobs: &[Job], threads: usize) -> (Vec<Row>, Duration) {
+    let next = AtomicUsize::new(0);
+    let rows = Mutex::new(vec![]);
+
+    // a panicking day is reported in the table, not on stderr as it happens
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let now = Instant::now();
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, jobs.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(job) = jobs.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let row = run_job(job);
+                    rows.lock().unwrap().push(row);
+                }
+            });
+        }
+    });
+    let wall = now.elapsed();
+
+    panic::set_hook(hook);
+
+    let mut rows = rows.into_inner().unwrap();
+    rows.sort_by_key(|row| (row.day, row.part));
+    (rows, wall)
+}
+
+#[derive(Serialize)]
+struct Report<'a> {
+    day: u32,
+    title: &'static str,
+    #[serde(flatten)]
+    outcome: Option<&'a Outcome>,
+    #[serde(flatten)]
+    failure: Option<&'a Failure>,
+}
+
+/// Prints the rows as a table, or as one line of JSON each, returning
+/// whether any day failed.
+pub fn print_summary(rows: &[Row], wall: Duration, format: Format) -> bool {
+    let total = rows.iter().map(Row::time).sum::<Duration>();
+
+    match format {
+        Format::Json => {
+            for row in rows.iter() {
+                let report = Report {
+                    day: row.day,
+                    title: row.title,
+                    outcome: row.result.as_ref().ok(),
+                    failure: row.result.as_ref().err(),
+                };
+                println!(
+                    "{}",
+                    serde_json::to_string(&report).expect("reports always serialize")
+                );
+            }
+        }
+        Format::Text => {
+            let mut table = Table::new(&["Day", "Title", "Part one", "Part two", "Time", "Share"]);
+            for row in rows.iter() {
+                let mut cells = vec![row.day.to_string(), String::from(row.title)];
+                match &row.result {
+                    Ok(outcome) => {
+                        let share = if total.is_zero() {
+                            0.0
+                        } else {
+                            100.0 * row.time().as_secs_f64() / total.as_secs_f64()
+                        };
+                        cells.extend([
+                            outcome.part_one.clone().unwrap_or_default(),
+                            outcome.part_two.clone().unwrap_or_default(),
+                            format_duration(row.time().as_nanos() as f64),
+                            format!("{:.1}%", share),
+                        ]);
+                    }
+                    Err(Failure::Error { .. }) => cells.push(String::from("error")),
+                    Err(Failure::Panic { .. }) => cells.push(String::from("panicked")),
+                }
+                table.push(cells);
+            }
+            print!("{}", table);
+            println!(
+                "Total: {} solving, {} wall time",
+                format_duration(total.as_nanos() as f64),
+                format_duration(wall.as_nanos() as f64)
+            );
+
+            for row in rows.iter() {
+                match &row.result {
+                    Err(Failure::Error { error }) => eprintln!("Day {}: {}", row.day, error),
+                    Err(Failure::Panic { message }) => {
+                        eprintln!("Day {} panicked: {}", row.day, message)
+                    }
+                    Ok(_) => {}
+                }
+            }
+        }
+    }
+
+    rows.iter().any(|row| row.result.is_err())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::process;
+
+    use super::*;
+    use crate::days::DAYS;
+
+    const DAY01: &str = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+
+    // two presents with the area to fit in a 4x4 region but not a 3x3 box
+    // each, which day 12 can't decide
+    const DAY12: &str = "0:\n###\n##.\n##.\n\n4x4: 2\n";
+
+    fn job(day: u32, input: &str) -> Job {
+        let dir = env::temp_dir().join(format!("aoc-summary-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("day{:02}.txt", day));
+        fs::write(&path, input).unwrap();
+
+        Job {
+            day: &DAYS[day as usize - 1],
+            input: path,
+            part: None,
+            params: Params::default(),
+        }
+    }
+
+    #[test]
+    fn sorted_with_failures_caught() {
+        let jobs = [job(12, DAY12), job(2, "not ranges"), job(1, DAY01)];
+        let (rows, _) = run_jobs(&jobs, 3);
+
+        assert_eq!(
+            rows.iter().map(|row| row.day).collect::<Vec<_>>(),
+            [1, 2, 12]
+        );
+
+        let outcome = rows[0].result.as_ref().unwrap();
+        assert_eq!(outcome.part_one.as_deref(), Some("3"));
+        assert_eq!(outcome.part_two.as_deref(), Some("6"));
+
+        assert!(matches!(rows[1].result, Err(Failure::Error { .. })));
+        match &rows[2].result {
+            Err(Failure::Panic { message }) => assert!(message.contains("not implemented")),
+            other => panic!("expected a panic, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn one_thread() {
+        let jobs = [job(1, DAY01)];
+        let (rows, _) = run_jobs(&jobs, 1);
+        assert_eq!(rows.len(), 1);
+        assert!(rows[0].result.is_ok());
+    }
+}
@@ -0,0 +1,1740 @@
+//! Day 1: Secret Entrance. Turning the safe dial and counting how often it
+//! points at zero, or at any other position, and a [`Lock`] of several dials
+//! chained like an odometer.
+
+use std::error;
+use std::fmt;
+use std::io::{self, BufRead};
+use std::str::FromStr;
+
+use aoc_common::{
+    Answer, Error, ParamError, Params, ParseError, Rng, Solution, Table, parse_token,
+};
+use tracing::{debug, debug_span, trace};
+
+/// Where the dial points before the first turn.
+pub const START: u32 = 50;
+/// The number of positions on the dial, 0 to 99.
+pub const DIAL_SIZE: u32 = 100;
+
+/// A turn of the dial: the direction, -1 for left and 1 for right, and the
+/// number of clicks.
+pub type Turn = (i32, u32);
+
+/// A turn of one dial of a [`Lock`], numbered from 0.
+pub type LockTurn = (usize, Turn);
+
+// a direction and a distance, which is a u64 for streamed turns
+fn parse_turn<T: FromStr>(input: &str, line: &str) -> Result<(i32, T), ParseError> {
+    let dir = match line.get(..1) {
+        Some("L") => -1,
+        Some("R") => 1,
+        _ => {
+            let token = line.get(..1).unwrap_or(line);
+            return Err(ParseError::at(input, token, "a direction, L or R"));
+        }
+    };
+    let dist = parse_token(input, &line[1..], "a distance")?;
+    Ok((dir, dist))
+}
+
+/// Parses one turn per line, like `L68` or `R48`.
+pub fn parse_input(input: &str) -> Result<Vec<Turn>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| parse_turn(input, line))
+        .collect()
+}
+
+/// Parses one turn per line for a lock, with the dial to turn before a
+/// colon, like `2:L68`. Turns without one are for the first dial, dial 0.
+pub fn parse_lock_input(input: &str) -> Result<Vec<LockTurn>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| match line.split_once(':') {
+            Some((dial, turn)) => Ok((
+                parse_token(input, dial, "a dial number")?,
+                parse_turn(input, turn)?,
+            )),
+            None => Ok((0, parse_turn(input, line)?)),
+        })
+        .collect()
+}
+
+/// Writes turns one per line, the way [`parse_input`] reads them.
+pub fn format_turns(turns: &[Turn]) -> String {
+    turns
+        .iter()
+        .map(|&(dir, dist)| format!("{}{}\n", if dir < 0 { 'L' } else { 'R' }, dist))
+        .collect()
+}
+
+/// Writes `size` turns of up to 999 clicks each.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let turns = (0..size)
+        .map(|_| {
+            let dir = if rng.chance(0.5) { -1 } else { 1 };
+            (dir, rng.between(1, 999) as u32)
+        })
+        .collect::<Vec<_>>();
+    format_turns(&turns)
+}
+
+/// The counts for [`generate_counts`] to hit: the turns that end at zero,
+/// and the clicks that point at it without the turn stopping there. With
+/// every turn at least one click long, [`solve`] gives `landed` and
+/// `landed + passed`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Counts {
+    pub landed: u64,
+    pub passed: u64,
+}
+
+/// Why [`generate_counts`] can't hit the counts asked for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CountsError {
+    TooManyLandings {
+        landed: u64,
+        turns: usize,
+    },
+    /// A dial with one position is at zero after every turn.
+    EveryTurnLands {
+        turns: usize,
+    },
+    /// Getting back to zero can take a full turn of the dial, and missing it
+    /// on a dial of two as much as two clicks.
+    TooShort {
+        max_dist: u32,
+        dial_size: u32,
+    },
+    /// The passes have to be between `least` and `most`.
+    Passes {
+        passed: u64,
+        least: u64,
+        most: u64,
+    },
+}
+
+impl fmt::Display for CountsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CountsError::TooManyLandings { landed, turns } => {
+                write!(f, "Can't land on zero {} times in {} turns", landed, turns)
+            }
+            CountsError::EveryTurnLands { turns } => write!(
+                f,
+                "Every turn of a dial with one position lands on zero, so all {} must",
+                turns
+            ),
+            CountsError::TooShort {
+                max_dist,
+                dial_size,
+            } => write!(
+                f,
+                "Turns of up to {} clicks can't always reach zero on a dial of {}",
+                max_dist, dial_size
+            ),
+            CountsError::Passes {
+                passed,
+                least,
+                most,
+            } => write!(
+                f,
+                "Can't pass zero {} times, only between {} and {} with these turns",
+                passed, least, most
+            ),
+        }
+    }
+}
+
+impl error::Error for CountsError {}
+
+/// Makes `turns` turns of 1 to `max_dist` clicks that hit `counts` exactly,
+/// the reverse of [`solve`], which checks them before they're returned.
+///
+/// Which turns land is picked at random, then how many passes each one
+/// makes, then a direction and distance that give those from wherever the
+/// dial is. The passes one turn can make are worked out for the worst place
+/// it could start from, so counts that would only fit with lucky positions
+/// are turned down.
+///
+/// # Panics
+///
+/// If the dial has no positions or `max_dist` is zero.
+pub fn generate_counts(
+    rng: &mut Rng,
+    start: u32,
+    dial_size: u32,
+    counts: Counts,
+    turns: usize,
+    max_dist: u32,
+) -> Result<Vec<Turn>, CountsError> {
+    assert!(dial_size > 0, "The dial needs at least one position");
+    assert!(max_dist > 0, "Turns need at least one click");
+    let size = dial_size as u64;
+    let max = max_dist as u64;
+    let start = start % dial_size;
+
+    if counts.landed > turns as u64 {
+        return Err(CountsError::TooManyLandings {
+            landed: counts.landed,
+            turns,
+        });
+    }
+    if size == 1 && counts.landed < turns as u64 {
+        return Err(CountsError::EveryTurnLands { turns });
+    }
+    if counts.landed > 0 && max < size {
+        return Err(CountsError::TooShort {
+            max_dist,
+            dial_size,
+        });
+    }
+
+    let mut lands = vec![false; turns];
+    lands[..counts.landed as usize].fill(true);
+    rng.shuffle(&mut lands);
+
+    // from anywhere, a landing is at most a full turn plus one more for
+    // each pass, and a turn that doesn't land can make a pass every full
+    // turn as long as it stops short of zero; on a dial of two, though, the
+    // dial is a click from zero everywhere but zero itself, where it only is
+    // at the start or after a landing, so it can only miss zero by going
+    // round to where it was
+    let bounds = (0..turns)
+        .map(|i| {
+            let at_zero = if i == 0 { start == 0 } else { lands[i - 1] };
+            if lands[i] {
+                (0, max / size - 1)
+            } else if size == 2 && !at_zero {
+                (1, max / 2)
+            } else {
+                (0, (max - 1) / size)
+            }
+        })
+        .collect::<Vec<_>>();
+    if bounds.iter().any(|&(least, most)| least > most) {
+        return Err(CountsError::TooShort {
+            max_dist,
+            dial_size,
+        });
+    }
+
+    let least = bounds.iter().map(|&(least, _)| least).sum::<u64>();
+    let most = bounds.iter().map(|&(_, most)| most).sum::<u64>();
+    if counts.passed < least || counts.passed > most {
+        return Err(CountsError::Passes {
+            passed: counts.passed,
+            least,
+            most,
+        });
+    }
+
+    // share out the passes above the least, leaving enough room in the
+    // turns still to come for whatever is left over
+    let mut extra = counts.passed - least;
+    let mut room = most - least;
+    let passes = bounds
+        .iter()
+        .map(|&(least, most)| {
+            room -= most - least;
+            let take = rng.between(extra.saturating_sub(room), extra.min(most - least));
+            extra -= take;
+            least + take
+        })
+        .collect::<Vec<_>>();
+
+    let mut pos = start;
+    let generated = lands
+        .iter()
+        .zip(passes.iter())
+        .map(|(&land, &passes)| {
+            // the clicks to the first time the dial points at zero each way
+            let first = |dir: i32| match pos {
+                0 => size,
+                _ if dir < 0 => pos as u64,
+                _ => size - pos as u64,
+            };
+            let options = [-1, 1]
+                .into_iter()
+                .filter_map(|dir| {
+                    let (lo, hi) = if land {
+                        let dist = first(dir) + passes * size;
+                        (dist, dist)
+                    } else if passes == 0 {
+                        (1, first(dir) - 1)
+                    } else {
+                        (
+                            first(dir) + (passes - 1) * size + 1,
+                            first(dir) + passes * size - 1,
+                        )
+                    };
+                    (lo <= hi.min(max)).then_some((dir, lo, hi.min(max)))
+                })
+                .collect::<Vec<_>>();
+
+            let &(dir, lo, hi) = rng.pick(&options);
+            let dist = rng.between(lo, hi) as u32;
+            pos = turn(pos, (dir, dist), dial_size).to;
+            (dir, dist)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        solve(&generated, start, dial_size),
+        (counts.landed, counts.landed + counts.passed),
+        "Generated turns that miss the counts"
+    );
+    Ok(generated)
+}
+
+/// One turn of the dial as [`trace`] saw it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub dir: i32,
+    pub dist: u32,
+    /// Where the dial pointed before the turn.
+    pub from: u32,
+    /// Where the dial points after it.
+    pub to: u32,
+    /// How many times the turn went all the way round.
+    pub full_turns: u32,
+    /// Whether what's left after the full turns went past zero without
+    /// stopping there.
+    pub crossed: bool,
+    /// Whether the turn ended at zero.
+    pub landed: bool,
+    /// The turns that ended at zero so far, the answer to part one.
+    pub landed_count: u64,
+    /// The clicks that pointed at zero so far, the answer to part two.
+    pub zero_count: u64,
+}
+
+// what one turn does to a dial pointing at `pos`
+struct Move {
+    to: u32,
+    full_turns: u32,
+    // whether the rest of the turn, after the full turns, reaches zero
+    reaches_zero: bool,
+    // how many times the dial went from the last position to zero turning
+    // right, or from zero to the last position turning left
+    wraps: u32,
+}
+
+impl Move {
+    // every full turn points at zero once, including the last one of a turn
+    // that starts and ends there, and the rest reaches zero if it gets as
+    // far as it
+    fn zero_clicks(&self) -> u64 {
+        self.full_turns as u64 + self.reaches_zero as u64
+    }
+}
+
+fn turn(pos: u32, (dir, dist): Turn, dial_size: u32) -> Move {
+    let full_turns = dist / dial_size;
+    let rest = dist % dial_size;
+
+    let (reaches_zero, wraps_round) = if dir < 0 {
+        (pos != 0 && rest >= pos, rest > pos)
+    } else {
+        // pos + rest could overflow on a dial more than half of u32::MAX
+        let reaches_zero = rest >= dial_size - pos;
+        (reaches_zero, reaches_zero)
+    };
+    let to = (pos as i64 + dir as i64 * rest as i64).rem_euclid(dial_size as i64) as u32;
+
+    Move {
+        to,
+        full_turns,
+        reaches_zero,
+        wraps: full_turns + wraps_round as u32,
+    }
+}
+
+/// Returns how many turns leave the dial at zero, and how many clicks in
+/// total point it at zero, whether at the end of a turn or passing through.
+pub fn solve(turns: &[Turn], start: u32, dial_size: u32) -> (u64, u64) {
+    let _span = debug_span!("dial", start, dial_size).entered();
+    let mut pos = start % dial_size;
+
+    let mut landed_count = 0;
+    let mut zero_count = 0;
+
+    for &t in turns.iter() {
+        let step = turn(pos, t, dial_size);
+        zero_count += step.zero_clicks();
+        trace!(
+            dir = t.0,
+            dist = t.1,
+            to = step.to,
+            zero_clicks = step.zero_clicks(),
+            "turned"
+        );
+
+        pos = step.to;
+        if pos == 0 {
+            landed_count += 1;
+        }
+    }
+
+    debug!(
+        turns = turns.len(),
+        landed_count, zero_count, "dial counted"
+    );
+    (landed_count, zero_count)
+}
+
+/// Follows the dial through every turn, keeping the running totals that
+/// [`solve`] adds up.
+pub fn trace(turns: &[Turn], start: u32, dial_size: u32) -> Vec<Step> {
+    let mut pos = start % dial_size;
+    let mut landed_count = 0;
+    let mut zero_count = 0;
+
+    turns
+        .iter()
+        .map(|&(dir, dist)| {
+            let step = turn(pos, (dir, dist), dial_size);
+            let landed = step.to == 0;
+            landed_count += landed as u64;
+            zero_count += step.zero_clicks();
+
+            let from = pos;
+            pos = step.to;
+            Step {
+                dir,
+                dist,
+                from,
+                to: step.to,
+                full_turns: step.full_turns,
+                crossed: step.reaches_zero && !landed,
+                landed,
+                landed_count,
+                zero_count,
+            }
+        })
+        .collect()
+}
+
+// the steps as a table for --trace
+fn trace_table(steps: &[Step]) -> Table {
+    let yes_no = |flag: bool| String::from(if flag { "yes" } else { "no" });
+
+    let mut table = Table::new(&[
+        "Turn",
+        "Dir",
+        "Dist",
+        "From",
+        "To",
+        "Full turns",
+        "Crossed",
+        "Landed",
+        "Landed total",
+        "Zero total",
+    ]);
+    for (i, step) in steps.iter().enumerate() {
+        table.push(vec![
+            (i + 1).to_string(),
+            String::from(if step.dir < 0 { "L" } else { "R" }),
+            step.dist.to_string(),
+            step.from.to_string(),
+            step.to.to_string(),
+            step.full_turns.to_string(),
+            yes_no(step.crossed),
+            yes_no(step.landed),
+            step.landed_count.to_string(),
+            step.zero_count.to_string(),
+        ]);
+    }
+    table
+}
+
+/// A dial counting how often it points at zero as the turns come, the way
+/// [`solve`] does, for [`solve_reader`]. Positions and distances are 64 bit
+/// and the counts 128 bit, so nothing overflows however big the dial is or
+/// however many turns there are.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RunningDial {
+    size: u64,
+    pos: u64,
+    landed: u128,
+    clicks: u128,
+}
+
+impl RunningDial {
+    /// # Panics
+    ///
+    /// If the dial has no positions.
+    pub fn new(start: u64, size: u64) -> Self {
+        assert!(size > 0, "The dial needs at least one position");
+        RunningDial {
+            size,
+            pos: start % size,
+            landed: 0,
+            clicks: 0,
+        }
+    }
+
+    pub fn turn(&mut self, dir: i32, dist: u64) {
+        let full_turns = dist / self.size;
+        let rest = dist % self.size;
+
+        // measured from the dial's two ends rather than by adding the rest
+        // to the position, which could overflow
+        let (reaches_zero, to) = if dir < 0 {
+            let to = if rest <= self.pos {
+                self.pos - rest
+            } else {
+                self.size - (rest - self.pos)
+            };
+            (self.pos != 0 && rest >= self.pos, to)
+        } else {
+            let to_zero = self.size - self.pos;
+            if rest >= to_zero {
+                (true, rest - to_zero)
+            } else {
+                (false, self.pos + rest)
+            }
+        };
+
+        self.clicks += full_turns as u128 + reaches_zero as u128;
+        self.pos = to;
+        if to == 0 {
+            self.landed += 1;
+        }
+    }
+
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+
+    /// The turns that ended at zero so far.
+    pub fn landed(&self) -> u128 {
+        self.landed
+    }
+
+    /// The clicks that pointed at zero so far.
+    pub fn clicks(&self) -> u128 {
+        self.clicks
+    }
+}
+
+// a turn of the one dial streaming follows, which lines for a lock number 0
+fn parse_streamed_turn(line: &str) -> Result<(i32, u64), ParseError> {
+    let Some((dial, turn)) = line.split_once(':') else {
+        return parse_turn(line, line);
+    };
+    if parse_token::<usize>(line, dial, "a dial number")? != 0 {
+        return Err(ParseError::at(
+            line,
+            dial,
+            "dial 0, as --stream only follows a single dial",
+        ));
+    }
+    parse_turn(line, turn)
+}
+
+/// Reads turns a line at a time, like [`parse_lock_input`] for a lock of one
+/// dial but with distances up to `u64::MAX`, and makes each one on all the
+/// dials as it comes, keeping none of them. Blank lines are skipped. A mistake in a line is an
+/// [`io::ErrorKind::InvalidData`] error wrapping a [`ParseError`].
+pub fn solve_reader<R: BufRead + ?Sized>(
+    reader: &mut R,
+    dials: &mut [RunningDial],
+) -> io::Result<()> {
+    let _span = debug_span!("stream", dials = dials.len()).entered();
+    let mut buf = String::new();
+    let mut line_number = 0;
+    loop {
+        buf.clear();
+        if reader.read_line(&mut buf)? == 0 {
+            debug!(lines = line_number, "stream read");
+            return Ok(());
+        }
+        line_number += 1;
+
+        let line = buf.trim_end_matches(['\n', '\r']);
+        if line.is_empty() {
+            continue;
+        }
+        let (dir, dist) = parse_streamed_turn(line)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.on_line(line_number)))?;
+        for dial in dials.iter_mut() {
+            dial.turn(dir, dist);
+        }
+    }
+}
+
+/// One dial of a [`Lock`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Dial {
+    pub start: u32,
+    pub size: u32,
+}
+
+/// How often something pointed at zero: the turns that ended there, and the
+/// clicks that pointed at it, passing through or not.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ZeroCount {
+    pub landed: u64,
+    pub clicks: u64,
+}
+
+/// What happened to a [`Lock`] over all its turns.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LockCount {
+    /// The counts for each dial, in order.
+    pub dials: Vec<ZeroCount>,
+    /// The counts for every dial together.
+    pub total: ZeroCount,
+    /// How many turns left every dial at zero.
+    pub opened: u64,
+    /// Where each dial points at the end.
+    pub positions: Vec<u32>,
+}
+
+/// One turn of a [`Lock`] as [`Lock::trace`] saw it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LockStep {
+    /// The dial turned, numbered from 0.
+    pub dial: usize,
+    pub dir: i32,
+    pub dist: u32,
+    /// How many dials moved: the one turned and the ones its carries reached.
+    pub moved: usize,
+    /// Where each dial points after the turn.
+    pub positions: Vec<u32>,
+    /// Whether the turn left every dial at zero.
+    pub opened: bool,
+    /// The counts for every dial together so far.
+    pub total: ZeroCount,
+}
+
+fn total(dials: &[ZeroCount]) -> ZeroCount {
+    ZeroCount {
+        landed: dials.iter().map(|count| count.landed).sum(),
+        clicks: dials.iter().map(|count| count.clicks).sum(),
+    }
+}
+
+/// Dials in a row, each carrying into the next like the wheels of an
+/// odometer: every time a dial goes round from its last position to zero
+/// the next one clicks once the same way, and going back from zero to the
+/// last position turns the next one back a click. The last dial's carries
+/// are lost.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lock {
+    dials: Vec<Dial>,
+}
+
+impl Lock {
+    /// # Panics
+    ///
+    /// If there are no dials, or one has no positions.
+    pub fn new(dials: Vec<Dial>) -> Self {
+        assert!(!dials.is_empty(), "The lock needs at least one dial");
+        assert!(
+            dials.iter().all(|dial| dial.size > 0),
+            "The dial needs at least one position"
+        );
+        Lock { dials }
+    }
+
+    pub fn dials(&self) -> &[Dial] {
+        &self.dials
+    }
+
+    fn starts(&self) -> Vec<u32> {
+        self.dials
+            .iter()
+            .map(|dial| dial.start % dial.size)
+            .collect()
+    }
+
+    // makes one turn and the carries it sets off, counting into `dials`,
+    // and returns how many dials moved
+    fn make_turn(
+        &self,
+        positions: &mut [u32],
+        dials: &mut [ZeroCount],
+        (first, (dir, dist)): LockTurn,
+    ) -> usize {
+        assert!(first < self.dials.len(), "The lock has no dial {}", first);
+
+        let mut dist = dist;
+        for i in first..self.dials.len() {
+            let step = turn(positions[i], (dir, dist), self.dials[i].size);
+            positions[i] = step.to;
+            dials[i].clicks += step.zero_clicks();
+            dials[i].landed += (step.to == 0) as u64;
+
+            dist = step.wraps;
+            if dist == 0 {
+                return i + 1 - first;
+            }
+        }
+        self.dials.len() - first
+    }
+
+    /// Makes the turns, counting how often each dial points at zero. A dial
+    /// turned by a carry counts as turned, so lands on zero if it stops
+    /// there.
+    ///
+    /// # Panics
+    ///
+    /// If a turn is for a dial the lock doesn't have.
+    pub fn solve(&self, turns: &[LockTurn]) -> LockCount {
+        let _span = debug_span!("lock", dials = self.dials.len()).entered();
+        let mut positions = self.starts();
+        let mut dials = vec![ZeroCount::default(); self.dials.len()];
+        let mut opened = 0;
+
+        for &t in turns.iter() {
+            let moved = self.make_turn(&mut positions, &mut dials, t);
+            trace!(dial = t.0, dir = t.1.0, dist = t.1.1, moved, "turned");
+            if positions.iter().all(|&pos| pos == 0) {
+                debug!(?positions, "opened");
+                opened += 1;
+            }
+        }
+        debug!(turns = turns.len(), opened, "lock counted");
+
+        LockCount {
+            total: total(&dials),
+            dials,
+            opened,
+            positions,
+        }
+    }
+
+    /// Follows the lock through every turn, keeping the running totals that
+    /// [`Lock::solve`] adds up.
+    ///
+    /// # Panics
+    ///
+    /// If a turn is for a dial the lock doesn't have.
+    pub fn trace(&self, turns: &[LockTurn]) -> Vec<LockStep> {
+        let mut positions = self.starts();
+        let mut dials = vec![ZeroCount::default(); self.dials.len()];
+
+        turns
+            .iter()
+            .map(|&(dial, (dir, dist))| {
+                let moved = self.make_turn(&mut positions, &mut dials, (dial, (dir, dist)));
+                LockStep {
+                    dial,
+                    dir,
+                    dist,
+                    moved,
+                    positions: positions.clone(),
+                    opened: positions.iter().all(|&pos| pos == 0),
+                    total: total(&dials),
+                }
+            })
+            .collect()
+    }
+}
+
+/// How often a dial pointed at a position.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Visit {
+    pub position: u32,
+    /// The turns that ended there.
+    pub landed: u64,
+    /// The clicks that pointed at it, whether the turn stopped there or not.
+    pub clicks: u64,
+}
+
+/// Counts the turns that end at `target` and the clicks that point at it,
+/// as [`solve`] does for zero. Pointing at `target` is pointing at zero on a
+/// dial turned back by `target` before the start.
+pub fn count_at(turns: &[Turn], start: u32, dial_size: u32, target: u32) -> Visit {
+    let size = dial_size as u64;
+    let shifted = ((start as u64 % size + size - target as u64 % size) % size) as u32;
+    let (landed, clicks) = solve(turns, shifted, dial_size);
+    Visit {
+        position: target,
+        landed,
+        clicks,
+    }
+}
+
+/// Counts the visits to every position of the dial, in order. Each full turn
+/// points once at every position, and the rest of a turn at a run of them
+/// next to each other, so however far the dial turns this takes one pass
+/// over the turns and one over the dial.
+pub fn visits(turns: &[Turn], start: u32, dial_size: u32) -> Vec<Visit> {
+    let size = dial_size as usize;
+    let mut landed = vec![0; size];
+    let mut full_turns = 0;
+    // the runs of positions as +1 where one starts and -1 after it ends,
+    // with runs that go past the end split in two
+    let mut runs = vec![0i64; size + 1];
+
+    let mut pos = start % dial_size;
+    for &(dir, dist) in turns.iter() {
+        let step = turn(pos, (dir, dist), dial_size);
+        full_turns += step.full_turns as u64;
+
+        // turning right from pos points at pos + 1 to the end, and turning
+        // left at the end to pos - 1
+        let rest = (dist % dial_size) as usize;
+        let first = if dir < 0 {
+            step.to
+        } else {
+            (pos + 1) % dial_size
+        } as usize;
+        if first + rest <= size {
+            runs[first] += 1;
+            runs[first + rest] -= 1;
+        } else {
+            runs[first] += 1;
+            runs[size] -= 1;
+            runs[0] += 1;
+            runs[first + rest - size] -= 1;
+        }
+
+        pos = step.to;
+        landed[pos as usize] += 1;
+    }
+
+    let mut in_runs = 0;
+    (0..size)
+        .map(|i| {
+            in_runs += runs[i];
+            Visit {
+                position: i as u32,
+                landed: landed[i],
+                clicks: full_turns + in_runs as u64,
+            }
+        })
+        .collect()
+}
+
+// the visits as a table for --visits
+fn visits_table(visits: &[Visit]) -> Table {
+    let mut table = Table::new(&["Position", "Landed", "Clicks"]);
+    for visit in visits.iter() {
+        table.push(vec![
+            visit.position.to_string(),
+            visit.landed.to_string(),
+            visit.clicks.to_string(),
+        ]);
+    }
+    table
+}
+
+// a lock's steps as a table for --trace, with where every dial points after
+// each turn
+fn lock_trace_table(steps: &[LockStep]) -> Table {
+    let mut table = Table::new(&[
+        "Turn",
+        "Dial",
+        "Dir",
+        "Dist",
+        "Dials moved",
+        "Positions",
+        "Opened",
+        "Landed total",
+        "Zero total",
+    ]);
+    for (i, step) in steps.iter().enumerate() {
+        let positions = step
+            .positions
+            .iter()
+            .map(|pos| pos.to_string())
+            .collect::<Vec<_>>();
+        table.push(vec![
+            (i + 1).to_string(),
+            step.dial.to_string(),
+            String::from(if step.dir < 0 { "L" } else { "R" }),
+            step.dist.to_string(),
+            step.moved.to_string(),
+            positions.join(" "),
+            String::from(if step.opened { "yes" } else { "no" }),
+            step.total.landed.to_string(),
+            step.total.clicks.to_string(),
+        ]);
+    }
+    table
+}
+
+// a lock's counts for each dial as a table for --visits, then the totals
+fn lock_table(lock: &Lock, count: &LockCount) -> Table {
+    let mut table = Table::new(&["Dial", "Size", "Start", "End", "Landed", "Clicks"]);
+    for (i, dial) in lock.dials().iter().enumerate() {
+        table.push(vec![
+            i.to_string(),
+            dial.size.to_string(),
+            dial.start.to_string(),
+            count.positions[i].to_string(),
+            count.dials[i].landed.to_string(),
+            count.dials[i].clicks.to_string(),
+        ]);
+    }
+    table.push(vec![
+        String::from("All"),
+        String::new(),
+        String::new(),
+        String::new(),
+        count.total.landed.to_string(),
+        count.total.clicks.to_string(),
+    ]);
+    table
+}
+
+// the sizes from --dial-size, a u32 for a lock and a u64 for streaming
+fn dial_sizes<T: FromStr + From<u8> + PartialEq>(
+    params: &Params,
+    default: T,
+) -> Result<Vec<T>, ParamError> {
+    let sizes = params.get_list("dial_size")?.unwrap_or(vec![default]);
+    if sizes.contains(&T::from(0)) {
+        return Err(ParamError::new(
+            "dial_size",
+            "a dial needs at least one position",
+        ));
+    }
+    Ok(sizes)
+}
+
+// points at the dial number of the first turn for a dial the lock doesn't
+// have, if there is one
+fn check_dial_numbers(input: &str, turns: &[LockTurn], lock: &Lock) -> Result<(), ParseError> {
+    let dials = lock.dials().len();
+    let Some(i) = turns.iter().position(|&(dial, _)| dial >= dials) else {
+        return Ok(());
+    };
+    // the turns are the lines parse_lock_input read, in order
+    let line = input.trim().lines().nth(i).unwrap_or_default();
+    let number = line.split_once(':').map_or(line, |(number, _)| number);
+    Err(ParseError::at(
+        input,
+        number,
+        format!("a dial number below {}", dials),
+    ))
+}
+
+// the dials from --dial-size and --start, which are lists for a lock of more
+// than one dial; dials without a start begin at zero, or at START for the
+// first one if there are no starts at all
+fn lock(params: &Params) -> Result<Lock, ParamError> {
+    let sizes = dial_sizes(params, DIAL_SIZE)?;
+    let starts = params.get_list("start")?.unwrap_or(vec![START]);
+    Ok(Lock::new(
+        sizes
+            .iter()
+            .enumerate()
+            .map(|(i, &size)| Dial {
+                start: starts.get(i).copied().unwrap_or(0),
+                size,
+            })
+            .collect(),
+    ))
+}
+
+// the turns and the dial for a lock with only one, or None for more
+fn single_dial(turns: &[LockTurn], params: &Params) -> Option<(Vec<Turn>, Dial)> {
+    let [dial] = lock(params).expect("checked before solving").dials()[..] else {
+        return None;
+    };
+    let turns = turns
+        .iter()
+        .map(|&(i, turn)| {
+            assert!(i == 0, "The lock has no dial {}", i);
+            turn
+        })
+        .collect();
+    Some((turns, dial))
+}
+
+// the positions from --targets in order, which are only for a single dial
+// and have to be on it, or None if there aren't any
+fn targets<T: FromStr + Ord + Copy + fmt::Display>(
+    params: &Params,
+    sizes: &[T],
+) -> Result<Option<Vec<T>>, ParamError> {
+    let Some(mut targets) = params.get_list::<T>("targets")? else {
+        return Ok(None);
+    };
+    let [size] = sizes[..] else {
+        return Err(ParamError::new(
+            "targets",
+            format!("only a single dial has targets, not {} dials", sizes.len()),
+        ));
+    };
+    if let Some(target) = targets.iter().find(|&&target| target >= size) {
+        return Err(ParamError::new(
+            "targets",
+            format!("{} is off a dial of {} positions", target, size),
+        ));
+    }
+    targets.sort_unstable();
+    targets.dedup();
+    Ok(Some(targets))
+}
+
+// the visits to each of --targets in order, or None if there aren't any
+fn target_visits(turns: &[LockTurn], params: &Params) -> Option<Vec<Visit>> {
+    let sizes = dial_sizes(params, DIAL_SIZE).expect("checked before solving");
+    let targets = targets(params, &sizes).expect("checked before solving")?;
+    let (turns, dial) = single_dial(turns, params)?;
+    Some(
+        targets
+            .into_iter()
+            .map(|target| count_at(&turns, dial.start, dial.size, target))
+            .collect(),
+    )
+}
+
+// the dials to stream a single dial's turns through, one for each of
+// --targets
+fn running_dials(params: &Params) -> Result<Vec<RunningDial>, ParamError> {
+    let sizes = dial_sizes(params, DIAL_SIZE as u64)?;
+    let targets = targets(params, &sizes)?.unwrap_or(vec![0]);
+    let [size] = sizes[..] else {
+        return Err(ParamError::new(
+            "dial_size",
+            format!("--stream only follows a single dial, not {}", sizes.len()),
+        ));
+    };
+    let start = params
+        .get_list::<u64>("start")?
+        .map_or(START as u64, |starts| starts[0])
+        % size;
+
+    Ok(targets
+        .into_iter()
+        .map(|target| {
+            let shifted = if start >= target {
+                start - target
+            } else {
+                size - (target - start)
+            };
+            RunningDial::new(shifted, size)
+        })
+        .collect())
+}
+
+/// The [`Solution`] for day 1, as run by `aoc run --day 1`.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Secret Entrance";
+    const INPUT_SIZE: usize = 4500;
+
+    type Input = Vec<LockTurn>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lock_input(input)
+    }
+
+    /// Every turn has to be for one of the dials from `--dial-size`, and
+    /// `--targets` on the one dial there is.
+    fn check(text: &str, turns: &Self::Input, _: Option<u8>, params: &Params) -> Result<(), Error> {
+        check_dial_numbers(text, turns, &lock(params)?)?;
+        targets(params, &dial_sizes(params, DIAL_SIZE)?)?;
+        Ok(())
+    }
+
+    /// Counts landings on any of `--targets` instead of zero, if given.
+    fn part_one(turns: &Self::Input, params: &Params) -> Self::PartOne {
+        match target_visits(turns, params) {
+            Some(visits) => visits.iter().map(|visit| visit.landed).sum(),
+            None => {
+                let lock = lock(params).expect("checked before solving");
+                lock.solve(turns).total.landed
+            }
+        }
+    }
+
+    /// Counts clicks pointing at any of `--targets` instead of zero, if
+    /// given.
+    fn part_two(turns: &Self::Input, params: &Params) -> Option<Self::PartTwo> {
+        Some(match target_visits(turns, params) {
+            Some(visits) => visits.iter().map(|visit| visit.clicks).sum(),
+            None => {
+                let lock = lock(params).expect("checked before solving");
+                lock.solve(turns).total.clicks
+            }
+        })
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+
+    /// A lock of more than one dial shows where each dial points after
+    /// every turn.
+    fn trace(turns: &Self::Input, params: &Params) -> Option<Table> {
+        Some(match single_dial(turns, params) {
+            Some((turns, dial)) => trace_table(&trace(&turns, dial.start, dial.size)),
+            None => {
+                let lock = lock(params).expect("checked before solving");
+                lock_trace_table(&lock.trace(turns))
+            }
+        })
+    }
+
+    /// Only for a single dial. Each of `--targets` is followed as zero on its
+    /// own dial, turned back by the target before the start as in
+    /// [`count_at`].
+    fn stream(
+        reader: &mut dyn BufRead,
+        params: &Params,
+    ) -> Option<io::Result<(Answer, Option<Answer>)>> {
+        let mut dials = match running_dials(params) {
+            Ok(dials) => dials,
+            Err(err) => return Some(Err(io::Error::new(io::ErrorKind::InvalidInput, err))),
+        };
+
+        Some(solve_reader(reader, &mut dials).map(|()| {
+            let landed = dials.iter().map(|dial| dial.landed()).sum::<u128>();
+            let clicks = dials.iter().map(|dial| dial.clicks()).sum::<u128>();
+            (Answer::from(landed), Some(Answer::from(clicks)))
+        }))
+    }
+
+    /// Every position of a single dial, or just `--targets`. A lock of more
+    /// than one dial has a row for each dial's counts at zero instead.
+    fn visits(turns: &Self::Input, params: &Params) -> Option<Table> {
+        if let Some(visits) = target_visits(turns, params) {
+            return Some(visits_table(&visits));
+        }
+        Some(match single_dial(turns, params) {
+            Some((turns, dial)) => visits_table(&visits(&turns, dial.start, dial.size)),
+            None => {
+                let lock = lock(params).expect("checked before solving");
+                lock_table(&lock, &lock.solve(turns))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::{Just, prop, prop_assert, prop_assert_eq, prop_oneof, proptest};
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82
+";
+
+    #[test]
+    fn example() {
+        let turns = parse_input(EXAMPLE).unwrap();
+        assert_eq!(solve(&turns, START, DIAL_SIZE), (3, 6));
+    }
+
+    #[test]
+    fn many_full_turns() {
+        // R1000 from 50 passes zero ten times without landing on it
+        assert_eq!(solve(&[(1, 1000)], START, DIAL_SIZE), (0, 10));
+    }
+
+    #[test]
+    fn landing_on_zero_is_not_also_a_pass() {
+        assert_eq!(solve(&[(-1, 50)], START, DIAL_SIZE), (1, 1));
+        assert_eq!(solve(&[(1, 50)], START, DIAL_SIZE), (1, 1));
+    }
+
+    #[test]
+    fn leaving_zero_is_not_a_pass() {
+        assert_eq!(solve(&[(-1, 50), (-1, 5)], START, DIAL_SIZE), (1, 1));
+        assert_eq!(solve(&[(-1, 50), (1, 5)], START, DIAL_SIZE), (1, 1));
+    }
+
+    #[test]
+    fn full_turns_from_zero() {
+        // the last of the full turns is the landing, not an extra pass
+        assert_eq!(solve(&[(-1, 50), (1, 200)], START, DIAL_SIZE), (2, 3));
+        assert_eq!(solve(&[(-1, 50), (-1, 100)], START, DIAL_SIZE), (2, 2));
+    }
+
+    #[test]
+    fn trace_matches_solve() {
+        let turns = parse_input(EXAMPLE).unwrap();
+        let steps = trace(&turns, START, DIAL_SIZE);
+        let last = steps.last().unwrap();
+        assert_eq!((last.landed_count, last.zero_count), (3, 6));
+
+        // L68 from 50 goes past zero to 82, and L30 then stops at 52
+        assert_eq!((steps[0].from, steps[0].to), (50, 82));
+        assert!(steps[0].crossed && !steps[0].landed);
+        assert!(!steps[1].crossed);
+
+        // R48 from 52 lands on zero, which isn't also a crossing
+        assert!(steps[2].landed && !steps[2].crossed);
+
+        let steps = trace(&[(1, 1000)], START, DIAL_SIZE);
+        assert_eq!(steps[0].full_turns, 10);
+        assert_eq!(steps[0].to, 50);
+    }
+
+    #[test]
+    fn dial_params() {
+        let params = |pairs: [(&str, &str); 2]| {
+            Params::new(pairs.map(|(name, value)| (String::from(name), String::from(value))))
+        };
+        assert_eq!(
+            lock(&Params::default()).unwrap().dials(),
+            [Dial {
+                start: START,
+                size: DIAL_SIZE
+            }]
+        );
+        assert_eq!(
+            lock(&params([("start", "5"), ("dial_size", "10")]))
+                .unwrap()
+                .dials(),
+            [Dial { start: 5, size: 10 }]
+        );
+        // a start past the end of the dial goes round
+        let lock_15 = lock(&params([("start", "15"), ("dial_size", "10")])).unwrap();
+        assert_eq!(lock_15.solve(&[]).positions, [5]);
+
+        // dials past the starts given begin at zero
+        let starts = lock(&params([("start", "5"), ("dial_size", "10,6,7")]))
+            .unwrap()
+            .dials()
+            .iter()
+            .map(|dial| dial.start)
+            .collect::<Vec<_>>();
+        assert_eq!(starts, [5, 0, 0]);
+
+        assert!(lock(&params([("start", "5"), ("dial_size", "ten")])).is_err());
+        assert!(lock(&params([("start", "5"), ("dial_size", "10,0")])).is_err());
+        assert!(running_dials(&params([("start", "5"), ("dial_size", "0")])).is_err());
+    }
+
+    #[test]
+    fn visits_to_every_position() {
+        let turns = parse_input(EXAMPLE).unwrap();
+        let all = visits(&turns, START, DIAL_SIZE);
+        assert_eq!((all[0].landed, all[0].clicks), (3, 6));
+        for target in [0, 25, 50, 99] {
+            assert_eq!(
+                count_at(&turns, START, DIAL_SIZE, target),
+                all[target as usize]
+            );
+        }
+
+        // every click points somewhere, and every turn ends somewhere
+        let dist = turns.iter().map(|&(_, dist)| dist as u64).sum::<u64>();
+        assert_eq!(all.iter().map(|visit| visit.clicks).sum::<u64>(), dist);
+        assert_eq!(all.iter().map(|visit| visit.landed).sum::<u64>(), 10);
+    }
+
+    #[test]
+    fn huge_turns() {
+        // ten million times round, then one click short of that back again,
+        // which misses only the position it started from
+        let turns = [(1, 1_000_000_000), (-1, 999_999_999)];
+        let all = visits(&turns, START, DIAL_SIZE);
+        assert_eq!(all[0].clicks, 20_000_000);
+        assert_eq!(
+            all[50],
+            Visit {
+                position: 50,
+                landed: 1,
+                clicks: 19_999_999
+            }
+        );
+        assert_eq!(all[51].landed, 1);
+        assert_eq!(count_at(&turns, START, DIAL_SIZE, 50), all[50]);
+    }
+
+    #[test]
+    fn dial_near_u32_max() {
+        // the dial's last position, then most of the way round past zero
+        let turns = [(1, 4_294_967_290)];
+        assert_eq!(solve(&turns, 4_294_967_294, u32::MAX), (0, 1));
+        assert_eq!(trace(&turns, 4_294_967_294, u32::MAX)[0].to, 4_294_967_289);
+        assert_eq!(
+            count_at(&turns, 4_294_967_294, u32::MAX, 4_294_967_289).landed,
+            1
+        );
+    }
+
+    #[test]
+    fn target_params() {
+        let turns = parse_lock_input(EXAMPLE).unwrap();
+        let params = Params::new([(String::from("targets"), String::from("50,0,25,0"))]);
+        assert_eq!(Day01::part_one(&turns, &params), 3);
+        assert_eq!(Day01::part_two(&turns, &params), Some(6 + 4 + 4));
+        assert_eq!(Day01::part_two(&turns, &Params::default()), Some(6));
+
+        let params = |pairs: &[(&str, &str)]| {
+            Params::new(
+                pairs
+                    .iter()
+                    .map(|&(name, value)| (String::from(name), String::from(value))),
+            )
+        };
+        let check = |pairs: &[(&str, &str)]| {
+            Day01::check(EXAMPLE, &turns, None, &params(pairs)).map_err(|err| err.to_string())
+        };
+        assert_eq!(
+            check(&[("targets", "0,200")]),
+            Err(String::from(
+                "Invalid parameter targets: 200 is off a dial of 100 positions"
+            ))
+        );
+        assert_eq!(
+            check(&[("targets", "0"), ("dial_size", "100,10")]),
+            Err(String::from(
+                "Invalid parameter targets: only a single dial has targets, not 2 dials"
+            ))
+        );
+        let streamed = Day01::stream(&mut EXAMPLE.as_bytes(), &params(&[("targets", "200")]));
+        let err = streamed.unwrap().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn streamed() {
+        let mut dials = [RunningDial::new(START as u64, DIAL_SIZE as u64)];
+        solve_reader(&mut EXAMPLE.as_bytes(), &mut dials).unwrap();
+        assert_eq!((dials[0].landed(), dials[0].clicks()), (3, 6));
+        assert_eq!(dials[0].position(), 32);
+
+        // with targets, as part one and part two count them
+        let params = Params::new([(String::from("targets"), String::from("0,25,50"))]);
+        let (part_one, part_two) = Day01::stream(&mut EXAMPLE.as_bytes(), &params)
+            .unwrap()
+            .unwrap();
+        let turns = parse_lock_input(EXAMPLE).unwrap();
+        assert_eq!(part_one, Answer::from(Day01::part_one(&turns, &params)));
+        assert_eq!(part_two, Day01::part_two(&turns, &params).map(Answer::from));
+    }
+
+    #[test]
+    fn streamed_without_overflow() {
+        // a dial as big as they come, turned further than a u32 reaches
+        let mut dials = [RunningDial::new(u64::MAX - 1, u64::MAX)];
+        solve_reader(
+            &mut "R1\nL18446744073709551615\nL10000000000\n".as_bytes(),
+            &mut dials,
+        )
+        .unwrap();
+        assert_eq!(dials[0].position(), u64::MAX - 10_000_000_000);
+        assert_eq!((dials[0].landed(), dials[0].clicks()), (2, 2));
+
+        // more clicks at zero than a u64 holds
+        let mut dials = [RunningDial::new(0, 1)];
+        let input = format!("R{}\n", u64::MAX).repeat(3);
+        solve_reader(&mut input.as_bytes(), &mut dials).unwrap();
+        assert_eq!(dials[0].clicks(), 3 * u64::MAX as u128);
+    }
+
+    #[test]
+    fn streamed_errors_keep_their_line() {
+        let mut dials = [RunningDial::new(0, 10)];
+        let err = solve_reader(&mut "L1\n\nR5\nR-2\n".as_bytes(), &mut dials).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let err = err.into_inner().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!((err.line(), err.column()), (4, 2));
+        assert_eq!(err.found(), "-2");
+    }
+
+    #[test]
+    fn streamed_lock_lines() {
+        // lines for the first dial of a lock are the single dial's turns
+        let mut dials = [RunningDial::new(5, 10)];
+        solve_reader(&mut "0:R5\nL3\n".as_bytes(), &mut dials).unwrap();
+        assert_eq!(dials[0].position(), 7);
+
+        let err = solve_reader(&mut "R1\n2:L3\n".as_bytes(), &mut dials).unwrap_err();
+        let err = err.into_inner().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!((err.line(), err.column()), (2, 1));
+        assert_eq!(
+            err.expected(),
+            "dial 0, as --stream only follows a single dial"
+        );
+
+        let params = Params::new([(String::from("dial_size"), String::from("10,10"))]);
+        let streamed = Day01::stream(&mut "0:R5\n1:L3\n".as_bytes(), &params);
+        let err = streamed.unwrap().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    fn decimal_lock(start: [u32; 3]) -> Lock {
+        Lock::new(start.map(|start| Dial { start, size: 10 }).to_vec())
+    }
+
+    #[test]
+    fn lock_carries_like_an_odometer() {
+        let lock = decimal_lock([0, 0, 0]);
+        assert_eq!(lock.solve(&[(0, (1, 1234))]).positions, [4, 3, 2]);
+        assert_eq!(lock.solve(&[(0, (-1, 1))]).positions, [9, 9, 9]);
+        assert_eq!(lock.solve(&[(1, (1, 25))]).positions, [0, 5, 2]);
+
+        // 999 + 1 rolls every dial over, and opens the lock
+        let count = decimal_lock([9, 9, 9]).solve(&[(0, (1, 1))]);
+        assert_eq!(count.positions, [0, 0, 0]);
+        assert_eq!(count.opened, 1);
+        assert_eq!(
+            count.total,
+            ZeroCount {
+                landed: 3,
+                clicks: 3
+            }
+        );
+    }
+
+    #[test]
+    fn lock_counts_each_dial() {
+        // reading the dials last to first: 095 - 15 = 080, with the first
+        // dial pointing at zero on its way down and again after a full turn,
+        // then 090 + 30 = 120 rolls the first dial round three times and the
+        // middle one once, and 120 - 300 borrows past the end
+        let turns = parse_lock_input("L15\n1:R1\nR30\n2:L3\n").unwrap();
+        assert_eq!(turns[1], (1, (1, 1)));
+        let count = decimal_lock([5, 9, 0]).solve(&turns);
+        assert_eq!(count.positions, [0, 2, 8]);
+        assert_eq!(
+            count.dials,
+            [
+                ZeroCount {
+                    landed: 2,
+                    clicks: 5
+                },
+                ZeroCount {
+                    landed: 0,
+                    clicks: 1
+                },
+                ZeroCount {
+                    landed: 0,
+                    clicks: 1
+                },
+            ]
+        );
+        assert_eq!(
+            count.total,
+            ZeroCount {
+                landed: 2,
+                clicks: 7
+            }
+        );
+        assert_eq!(count.opened, 0);
+    }
+
+    #[test]
+    fn lock_trace_matches_solve() {
+        let turns = parse_lock_input("L15\n1:R1\nR30\n2:L3\nR20\n").unwrap();
+        let lock = decimal_lock([5, 9, 0]);
+        let steps = lock.trace(&turns);
+        let count = lock.solve(&turns);
+        assert_eq!(
+            steps.iter().map(|step| step.moved).collect::<Vec<_>>(),
+            [2, 1, 3, 1, 2]
+        );
+        let last = steps.last().unwrap();
+        assert_eq!(last.positions, count.positions);
+        assert_eq!(last.total, count.total);
+        assert_eq!(
+            steps.iter().filter(|step| step.opened).count() as u64,
+            count.opened
+        );
+
+        // the CLI shows both for a lock, with a row for each dial and the
+        // totals under them
+        let params = Params::new([(String::from("dial_size"), String::from("10,10,10"))]);
+        let table = Day01::trace(&turns, &params).unwrap().to_csv();
+        assert_eq!(table.lines().count(), 1 + turns.len());
+        let table = Day01::visits(&turns, &params).unwrap().to_csv();
+        assert_eq!(table.lines().count(), 1 + 3 + 1);
+    }
+
+    #[test]
+    fn bad_dial_number() {
+        let err = parse_lock_input("L1\nx:R5\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 1));
+        assert_eq!(err.expected(), "a dial number");
+    }
+
+    #[test]
+    fn dial_numbers_past_the_lock() {
+        let input = "L1\n1:R5\n3:R5\n";
+        let turns = parse_lock_input(input).unwrap();
+        let params = Params::new([(String::from("dial_size"), String::from("10,10"))]);
+        let Err(Error::Parse(err)) = Day01::check(input, &turns, None, &params) else {
+            panic!("dial 3 of two should be an error");
+        };
+        assert_eq!((err.line(), err.column()), (3, 1));
+        assert_eq!(err.found(), "3");
+        assert_eq!(err.expected(), "a dial number below 2");
+
+        let err = Day01::check(input, &turns, None, &Params::default()).unwrap_err();
+        assert!(matches!(err, Error::Parse(err) if err.line() == 2));
+    }
+
+    // turns the dial one click at a time, counting the turns that end at
+    // zero and the clicks that point at it
+    fn click_by_click(turns: &[Turn], start: u32, dial_size: u32) -> (u64, u64) {
+        let mut pos = start % dial_size;
+        let (mut landed, mut clicks) = (0, 0);
+        for &(dir, dist) in turns.iter() {
+            for _ in 0..dist {
+                pos = (pos + if dir < 0 { dial_size - 1 } else { 1 }) % dial_size;
+                if pos == 0 {
+                    clicks += 1;
+                }
+            }
+            if pos == 0 {
+                landed += 1;
+            }
+        }
+        (landed, clicks)
+    }
+
+    // the lock one click at a time, each click of a dial that goes round
+    // clicking the next
+    fn click_lock(turns: &[LockTurn], dials: &[Dial]) -> LockCount {
+        struct Clicker<'a> {
+            dials: &'a [Dial],
+            positions: Vec<u32>,
+            moved: Vec<bool>,
+            count: LockCount,
+        }
+
+        impl Clicker<'_> {
+            fn click(&mut self, i: usize, dir: i32) {
+                let size = self.dials[i].size;
+                let from = self.positions[i];
+                self.positions[i] = (from + if dir < 0 { size - 1 } else { 1 }) % size;
+                self.moved[i] = true;
+                if self.positions[i] == 0 {
+                    self.count.dials[i].clicks += 1;
+                }
+                let wrapped = if dir < 0 {
+                    from == 0
+                } else {
+                    self.positions[i] == 0
+                };
+                if wrapped && i + 1 < self.dials.len() {
+                    self.click(i + 1, dir);
+                }
+            }
+        }
+
+        let mut clicker = Clicker {
+            dials,
+            positions: dials.iter().map(|dial| dial.start % dial.size).collect(),
+            moved: vec![],
+            count: LockCount {
+                dials: vec![ZeroCount::default(); dials.len()],
+                total: ZeroCount::default(),
+                opened: 0,
+                positions: vec![],
+            },
+        };
+        for &(first, (dir, dist)) in turns.iter() {
+            // the dial turned counts as moved even for no clicks
+            clicker.moved = vec![false; dials.len()];
+            clicker.moved[first] = true;
+            for _ in 0..dist {
+                clicker.click(first, dir);
+            }
+            for i in 0..dials.len() {
+                if clicker.moved[i] && clicker.positions[i] == 0 {
+                    clicker.count.dials[i].landed += 1;
+                }
+            }
+            if clicker.positions.iter().all(|&pos| pos == 0) {
+                clicker.count.opened += 1;
+            }
+        }
+
+        let mut count = clicker.count;
+        count.total = ZeroCount {
+            landed: count.dials.iter().map(|dial| dial.landed).sum(),
+            clicks: count.dials.iter().map(|dial| dial.clicks).sum(),
+        };
+        count.positions = clicker.positions;
+        count
+    }
+
+    // the visits to each position, one click at a time
+    fn click_visits(turns: &[Turn], start: u32, dial_size: u32) -> Vec<Visit> {
+        let mut all = (0..dial_size)
+            .map(|position| Visit {
+                position,
+                ..Visit::default()
+            })
+            .collect::<Vec<_>>();
+        let mut pos = start % dial_size;
+        for &(dir, dist) in turns.iter() {
+            for _ in 0..dist {
+                pos = (pos + if dir < 0 { dial_size - 1 } else { 1 }) % dial_size;
+                all[pos as usize].clicks += 1;
+            }
+            all[pos as usize].landed += 1;
+        }
+        all
+    }
+
+    proptest! {
+        #[test]
+        fn generated_counts_check_out(
+            seed in 0..1000u64,
+            start in 0..20u32,
+            dial_size in 1..20u32,
+            turns in 0..30usize,
+            max_dist in 1..100u32,
+            landed in 0..30u64,
+            passed in 0..1000u64,
+        ) {
+            let counts = Counts { landed, passed };
+            match generate_counts(&mut Rng::new(seed), start, dial_size, counts, turns, max_dist) {
+                Ok(generated) => {
+                    prop_assert_eq!(generated.len(), turns);
+                    prop_assert!(generated.iter().all(|&(_, dist)| (1..=max_dist).contains(&dist)));
+                    prop_assert_eq!(solve(&generated, start, dial_size), (landed, landed + passed));
+                }
+                Err(CountsError::Passes { least, most, .. }) => {
+                    prop_assert!(passed < least || passed > most);
+
+                    // and anything in between can be had
+                    let passed = least + passed % (most - least + 1);
+                    let counts = Counts { landed, passed };
+                    let generated = generate_counts(
+                        &mut Rng::new(seed), start, dial_size, counts, turns, max_dist,
+                    );
+                    prop_assert!(generated.is_ok(), "{:?}", generated);
+                }
+                Err(_) => {}
+            }
+        }
+
+        #[test]
+        fn visits_agree_with_clicking(
+            turns in prop::collection::vec((prop_oneof![Just(-1), Just(1)], 0..350u32), 0..30),
+            start in 0..20u32,
+            dial_size in 1..20u32,
+        ) {
+            let all = visits(&turns, start, dial_size);
+            prop_assert_eq!(&all, &click_visits(&turns, start, dial_size));
+            for target in 0..dial_size {
+                prop_assert_eq!(count_at(&turns, start, dial_size, target), all[target as usize]);
+            }
+        }
+
+        #[test]
+        fn lock_agrees_with_clicking(
+            dials in prop::collection::vec((0..8u32, 1..6u32), 1..4),
+            turns in prop::collection::vec(
+                (0..4usize, (prop_oneof![Just(-1), Just(1)], 0..80u32)),
+                0..20,
+            ),
+        ) {
+            let dials = dials
+                .into_iter()
+                .map(|(start, size)| Dial { start, size })
+                .collect::<Vec<_>>();
+            let turns = turns
+                .into_iter()
+                .map(|(i, turn)| (i % dials.len(), turn))
+                .collect::<Vec<_>>();
+            prop_assert_eq!(Lock::new(dials.clone()).solve(&turns), click_lock(&turns, &dials));
+        }
+
+        #[test]
+        fn agrees_with_clicking(
+            turns in prop::collection::vec((prop_oneof![Just(-1), Just(1)], 0..350u32), 0..30),
+            start in 0..20u32,
+            dial_size in 1..20u32,
+        ) {
+            let answers = solve(&turns, start, dial_size);
+            prop_assert_eq!(answers, click_by_click(&turns, start, dial_size));
+
+            let totals = trace(&turns, start, dial_size)
+                .last()
+                .map_or((0, 0), |step| (step.landed_count, step.zero_count));
+            prop_assert_eq!(totals, answers);
+
+            // and so is a running dial
+            let mut running = [RunningDial::new(start as u64, dial_size as u64)];
+            for &(dir, dist) in turns.iter() {
+                running[0].turn(dir, dist as u64);
+            }
+            prop_assert_eq!((running[0].landed() as u64, running[0].clicks() as u64), answers);
+
+            // a lock of one dial is the same dial
+            let lock_turns = turns.iter().map(|&turn| (0, turn)).collect::<Vec<_>>();
+            let count = Lock::new(vec![Dial { start, size: dial_size }]).solve(&lock_turns);
+            prop_assert_eq!((count.total.landed, count.total.clicks), answers);
+        }
+    }
+
+    #[test]
+    fn bad_direction() {
+        let err = parse_input("L1\nX5\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 1));
+        assert_eq!(err.found(), "X");
+    }
+
+    #[test]
+    fn generated_counts() {
+        let counts = Counts {
+            landed: 3,
+            passed: 3,
+        };
+        let turns = generate_counts(&mut Rng::new(1), START, DIAL_SIZE, counts, 10, 999).unwrap();
+        assert_eq!(turns.len(), 10);
+        assert!(turns.iter().all(|&(_, dist)| (1..=999).contains(&dist)));
+        assert_eq!(solve(&turns, START, DIAL_SIZE), (3, 6));
+        assert_eq!(parse_input(&format_turns(&turns)).unwrap(), turns);
+    }
+
+    #[test]
+    fn counts_out_of_reach() {
+        let generate = |size, counts, turns, max| {
+            generate_counts(&mut Rng::new(0), 0, size, counts, turns, max).unwrap_err()
+        };
+        let counts = |landed, passed| Counts { landed, passed };
+
+        assert_eq!(
+            generate(100, counts(4, 0), 3, 999),
+            CountsError::TooManyLandings {
+                landed: 4,
+                turns: 3
+            }
+        );
+        assert_eq!(
+            generate(1, counts(2, 0), 3, 999),
+            CountsError::EveryTurnLands { turns: 3 }
+        );
+        assert_eq!(
+            generate(100, counts(1, 0), 3, 99),
+            CountsError::TooShort {
+                max_dist: 99,
+                dial_size: 100
+            }
+        );
+        // each turn of up to 999 clicks can go round nine times
+        assert_eq!(
+            generate(100, counts(0, 28), 3, 999),
+            CountsError::Passes {
+                passed: 28,
+                least: 0,
+                most: 27
+            }
+        );
+    }
+}
@@ -0,0 +1,175 @@
+//! Day 2: Gift Shop. Finding product IDs made of a repeated sequence of
+//! digits.
+
+use aoc_common::{Params, ParseError, Rng, Solution, parse_token};
+use tracing::{debug, debug_span, trace};
+
+/// An inclusive range of product IDs.
+pub type IdRange = (u64, u64);
+
+/// Parses a comma separated list of ranges, like `11-22,95-115`.
+pub fn parse_input(input: &str) -> Result<Vec<IdRange>, ParseError> {
+    input
+        .trim()
+        .split(',')
+        .map(|range| {
+            let (lower, upper) = range
+                .split_once('-')
+                .ok_or_else(|| ParseError::at(input, range, "a range like 11-22"))?;
+            Ok((
+                parse_token(input, lower, "a number")?,
+                parse_token(input, upper, "a number")?,
+            ))
+        })
+        .collect()
+}
+
+/// Writes `size` ranges of up to 10,000 IDs each, at least one, starting at
+/// IDs of two to ten digits.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let ranges = (0..size.max(1))
+        .map(|_| {
+            let digits = rng.between(2, 10) as u32;
+            let lower = rng.between(10u64.pow(digits - 1), 10u64.pow(digits) - 1);
+            format!("{}-{}", lower, lower + rng.below(10_000))
+        })
+        .collect::<Vec<_>>();
+    ranges.join(",") + "\n"
+}
+
+fn is_invalid_helper(s: &str, repeats: usize) -> bool {
+    // regex crate doesn't support backreference, so here we are
+    if !s.len().is_multiple_of(repeats) {
+        return false;
+    }
+
+    let seg_length = s.len() / repeats;
+
+    let to_match = &s[0..seg_length];
+
+    for i in 1..repeats {
+        if &s[(seg_length * i)..(seg_length * (i + 1))] != to_match {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Whether the ID is some sequence of digits repeated exactly twice.
+pub fn is_invalid_part_one(id: u64) -> bool {
+    let s = id.to_string();
+
+    is_invalid_helper(&s, 2)
+}
+
+/// Whether the ID is some sequence of digits repeated at least twice.
+pub fn is_invalid_part_two(id: u64) -> bool {
+    let s = id.to_string();
+
+    (2..=s.len()).any(|r| is_invalid_helper(&s, r))
+}
+
+/// Sums the IDs in the ranges that are a sequence repeated twice.
+pub fn solve_part_one(ranges: &[IdRange]) -> u64 {
+    let _span = debug_span!("invalid_ids", repeats = "twice").entered();
+    sum_invalid(ranges, is_invalid_part_one)
+}
+
+/// Sums the IDs in the ranges that are a sequence repeated any number of
+/// times.
+pub fn solve_part_two(ranges: &[IdRange]) -> u64 {
+    let _span = debug_span!("invalid_ids", repeats = "at least twice").entered();
+    sum_invalid(ranges, is_invalid_part_two)
+}
+
+fn sum_invalid(ranges: &[IdRange], is_invalid: fn(u64) -> bool) -> u64 {
+    let total = ranges
+        .iter()
+        .map(|&(lower, upper)| {
+            let sum = (lower..=upper).filter(|&id| is_invalid(id)).sum::<u64>();
+            trace!(lower, upper, sum, "range checked");
+            sum
+        })
+        .sum();
+    debug!(ranges = ranges.len(), total, "invalid IDs summed");
+    total
+}
+
+/// The [`Solution`] for day 2, as run by `aoc run --day 2`.
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    const TITLE: &'static str = "Gift Shop";
+    const INPUT_SIZE: usize = 35;
+
+    type Input = Vec<IdRange>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(ranges: &Self::Input, _params: &Params) -> Self::PartOne {
+        solve_part_one(ranges)
+    }
+
+    fn part_two(ranges: &Self::Input, _params: &Params) -> Option<Self::PartTwo> {
+        Some(solve_part_two(ranges))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,\
+1698522-1698528,446443-446449,38593856-38593862,565653-565659,\
+824824821-824824827,2121212118-2121212124";
+
+    #[test]
+    fn repeated_twice() {
+        assert!(is_invalid_helper("55", 2));
+        assert!(is_invalid_helper("6464", 2));
+        assert!(is_invalid_helper("123123", 2));
+        assert!(!is_invalid_helper("101", 2));
+        assert!(!is_invalid_helper("1234", 2));
+    }
+
+    #[test]
+    fn repeated_more_times() {
+        assert!(is_invalid_helper("12341234", 2));
+        assert!(is_invalid_helper("123123123", 3));
+        assert!(is_invalid_helper("1212121212", 5));
+        assert!(is_invalid_helper("1111111", 7));
+        assert!(!is_invalid_helper("123123123", 2));
+        assert!(!is_invalid_helper("1212121213", 5));
+    }
+
+    #[test]
+    fn invalid_ids_in_ranges() {
+        assert_eq!(solve_part_one(&[(11, 22)]), 11 + 22);
+        assert_eq!(solve_part_one(&[(1698522, 1698528)]), 0);
+        assert_eq!(solve_part_two(&[(95, 115)]), 99 + 111);
+        assert_eq!(solve_part_two(&[(2121212118, 2121212124)]), 2121212121);
+    }
+
+    #[test]
+    fn example() {
+        let ranges = parse_input(EXAMPLE).unwrap();
+        assert_eq!(solve_part_one(&ranges), 1227775554);
+        assert_eq!(solve_part_two(&ranges), 4174379265);
+    }
+
+    #[test]
+    fn bad_range() {
+        let err = parse_input("11-22,95").unwrap_err();
+        assert_eq!(err.found(), "95");
+    }
+}
@@ -0,0 +1,276 @@
+//! Day 3: Lobby. Picking the batteries in each bank that give the most
+//! joltage.
+
+use aoc_common::{Answer, Error, ParamError, Params, ParseError, Rng, Solution};
+use tracing::{debug, debug_span, trace};
+
+/// Parses one bank of battery joltages per line, a digit per battery.
+pub fn parse_input(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            line.char_indices()
+                .map(|(i, c)| {
+                    c.to_digit(10).ok_or_else(|| {
+                        ParseError::at(input, &line[i..(i + c.len_utf8())], "a digit")
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()
+}
+
+/// Writes `size` banks of 100 batteries each.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let bank = (0..100)
+                .map(|_| char::from(b'0' + rng.between(1, 9) as u8))
+                .collect::<String>();
+            bank + "\n"
+        })
+        .collect()
+}
+
+/// Sums the largest joltage from each bank when turning on `group_count`
+/// batteries.
+pub fn solve(banks: &[Vec<u32>], group_count: usize) -> Answer {
+    let _span = debug_span!("banks", group_count).entered();
+    let total = banks
+        .iter()
+        .map(|bank| {
+            let joltage = largest_group(bank, group_count);
+            trace!(batteries = bank.len(), %joltage, "bank");
+            joltage
+        })
+        .sum::<Answer>();
+    debug!(banks = banks.len(), %total, "joltage summed");
+    total
+}
+
+/// The largest number made by picking `group_count` digits from the bank,
+/// keeping their order. Groups of more than 19 digits can be too big for a
+/// `u64`, and come back as [`Answer::Big`].
+///
+/// # Panics
+///
+/// If the bank has fewer than `group_count` batteries.
+pub fn largest_group(bank: &[u32], group_count: usize) -> Answer {
+    let mut res = Answer::default();
+    let mut start_idx = 0;
+
+    for i in 0..group_count {
+        let (idx, elem) = bank
+            .iter()
+            .enumerate()
+            .take(bank.len() - (group_count - i - 1))
+            .skip(start_idx)
+            .rev() // in the case of a tie, max_by_key returns the last, while we need the first
+            .max_by_key(|pair| pair.1)
+            .unwrap();
+        start_idx = idx + 1;
+        res = res * 10 + *elem;
+    }
+
+    res
+}
+
+/// How many batteries to turn on in each bank for part one and part two.
+pub const GROUPS: [usize; 2] = [2, 12];
+
+// the group counts from --groups, falling back to the puzzle's for any part
+// not given
+fn groups(params: &Params) -> Result<[usize; 2], ParamError> {
+    let given = params.get_list::<usize>("groups")?.unwrap_or_default();
+    Ok([0, 1].map(|part| given.get(part).copied().unwrap_or(GROUPS[part])))
+}
+
+/// The [`Solution`] for day 3, as run by `aoc run --day 3`.
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    const TITLE: &'static str = "Lobby";
+    const INPUT_SIZE: usize = 200;
+
+    type Input = Vec<Vec<u32>>;
+    type PartOne = Answer;
+    type PartTwo = Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    /// Every bank needs as many batteries as each part turns on.
+    fn check(_: &str, banks: &Self::Input, part: Option<u8>, params: &Params) -> Result<(), Error> {
+        let groups = groups(params)?;
+        let Some(shortest) = banks.iter().map(Vec::len).min() else {
+            return Ok(());
+        };
+        for (count, n) in groups.into_iter().zip(1..) {
+            if part.is_none_or(|part| part == n) && count > shortest {
+                return Err(ParamError::new(
+                    "groups",
+                    format!(
+                        "can't turn on {} batteries in a bank of {}",
+                        count, shortest
+                    ),
+                )
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    fn part_one(banks: &Self::Input, params: &Params) -> Self::PartOne {
+        solve(banks, groups(params).expect("checked before solving")[0])
+    }
+
+    fn part_two(banks: &Self::Input, params: &Params) -> Option<Self::PartTwo> {
+        Some(solve(
+            banks,
+            groups(params).expect("checked before solving")[1],
+        ))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::{Strategy, prop, prop_assert_eq, proptest};
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+987654321111111
+811111111111119
+234234234234278
+818181911112111
+";
+
+    fn bank(digits: &str) -> Vec<u32> {
+        digits.chars().map(|c| c.to_digit(10).unwrap()).collect()
+    }
+
+    #[test]
+    fn largest_pairs() {
+        assert_eq!(
+            largest_group(&bank("987654321111111"), 2),
+            Answer::from(98u64)
+        );
+        assert_eq!(
+            largest_group(&bank("811111111111119"), 2),
+            Answer::from(89u64)
+        );
+        assert_eq!(
+            largest_group(&bank("234234234234278"), 2),
+            Answer::from(78u64)
+        );
+        assert_eq!(
+            largest_group(&bank("818181911112111"), 2),
+            Answer::from(92u64)
+        );
+    }
+
+    #[test]
+    fn largest_twelves() {
+        assert_eq!(
+            largest_group(&bank("987654321111111"), 12),
+            Answer::from(987654321111u64)
+        );
+        assert_eq!(
+            largest_group(&bank("811111111111119"), 12),
+            Answer::from(811111111119u64)
+        );
+        assert_eq!(
+            largest_group(&bank("234234234234278"), 12),
+            Answer::from(434234234278u64)
+        );
+        assert_eq!(
+            largest_group(&bank("818181911112111"), 12),
+            Answer::from(888911112111u64)
+        );
+    }
+
+    #[test]
+    fn whole_bank() {
+        assert_eq!(largest_group(&bank("4213"), 4), Answer::from(4213u64));
+    }
+
+    #[test]
+    fn ties_take_the_first() {
+        assert_eq!(largest_group(&bank("9919"), 3), Answer::from(999u64));
+    }
+
+    #[test]
+    fn example() {
+        let banks = parse_input(EXAMPLE).unwrap();
+        assert_eq!(solve(&banks, 2), Answer::from(357u64));
+        assert_eq!(solve(&banks, 12), Answer::from(3121910778619u64));
+    }
+
+    #[test]
+    fn groups_too_big_for_u64() {
+        let digits = "98765432109876543210987654321";
+        assert_eq!(largest_group(&bank(digits), 29).to_string(), digits);
+    }
+
+    #[test]
+    fn group_params() {
+        let params = |groups: &str| Params::new([(String::from("groups"), String::from(groups))]);
+        assert_eq!(groups(&Params::default()), Ok(GROUPS));
+        assert_eq!(groups(&params("3,5")), Ok([3, 5]));
+        assert_eq!(groups(&params("3")), Ok([3, 12]));
+        assert!(groups(&params("3,x")).is_err());
+
+        let banks = parse_input(EXAMPLE).unwrap();
+        assert!(Day03::check(EXAMPLE, &banks, None, &params("2,15")).is_ok());
+        let err = Day03::check(EXAMPLE, &banks, None, &params("2,16")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid parameter groups: can't turn on 16 batteries in a bank of 15"
+        );
+        assert!(Day03::check(EXAMPLE, &banks, Some(1), &params("2,16")).is_ok());
+    }
+
+    #[test]
+    fn bad_digit() {
+        let err = parse_input("123\n4x6\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 2));
+    }
+
+    // a bank of up to ten batteries and how many of them to turn on
+    fn small_bank() -> impl Strategy<Value = (Vec<u32>, usize)> {
+        prop::collection::vec(1..=9u32, 1..=10)
+            .prop_flat_map(|bank| (1..=bank.len()).prop_map(move |count| (bank.clone(), count)))
+    }
+
+    // tries every choice of `group_count` batteries, as a bitmask over the
+    // bank
+    fn largest_by_trying_all(bank: &[u32], group_count: usize) -> u64 {
+        (0..1u32 << bank.len())
+            .filter(|picked| picked.count_ones() as usize == group_count)
+            .map(|picked| {
+                (0..bank.len())
+                    .filter(|i| picked & (1 << i) != 0)
+                    .fold(0, |joltage, i| joltage * 10 + bank[i] as u64)
+            })
+            .max()
+            .unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_trying_every_group((bank, group_count) in small_bank()) {
+            prop_assert_eq!(
+                largest_group(&bank, group_count),
+                Answer::from(largest_by_trying_all(&bank, group_count))
+            );
+        }
+    }
+}
@@ -0,0 +1,239 @@
+//! Day 4: Printing Department. Finding the rolls of paper a forklift can
+//! reach.
+
+use aoc_common::{Error, Grid, ParamError, Params, ParseError, Rng, Solution};
+use tracing::{debug, debug_span, trace};
+
+/// A roll with fewer than this many rolls around it can be reached.
+pub const THRESHOLD: usize = 4;
+
+/// Parses the grid of rolls, true where there is a roll, `@`, and false for
+/// empty space, `.`.
+pub fn parse_input(input: &str) -> Result<Grid<bool>, ParseError> {
+    Grid::parse(
+        input,
+        |c| match c {
+            '@' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        },
+        "a roll of paper, @, or an empty space, .",
+    )
+}
+
+/// Writes a `size` by `size` grid, about two thirds of it rolls.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    Grid::from_fn(size, size, |_| if rng.chance(0.65) { '@' } else { '.' }).to_string()
+}
+
+/// Removes every roll with fewer than `threshold` rolls around it, returning
+/// how many were removed and the grid left behind.
+pub fn remove(grid: &Grid<bool>, threshold: usize) -> (usize, Grid<bool>) {
+    let mut removed = 0;
+    let updated_grid = Grid::from_fn(grid.width(), grid.height(), |pos| {
+        if !grid[pos] {
+            return false;
+        }
+
+        let num_neighbors = grid.neighbors8(pos).filter(|&n| grid[n]).count();
+        if num_neighbors < threshold {
+            removed += 1;
+            false
+        } else {
+            true
+        }
+    });
+
+    (removed, updated_grid)
+}
+
+/// Returns how many rolls can be removed straight away, and how many can be
+/// removed in total by removing rolls until none are left that can be.
+pub fn solve(grid: &Grid<bool>, threshold: usize) -> (usize, usize) {
+    let _span = debug_span!("rolls", threshold).entered();
+    let (mut part_two, mut grid) = remove(grid, threshold);
+    let part_one = part_two;
+
+    let mut rounds = 1;
+    loop {
+        let (r, g) = remove(&grid, threshold);
+        trace!(round = rounds + 1, removed = r, "removed rolls");
+        if r == 0 {
+            break;
+        }
+        rounds += 1;
+        part_two += r;
+        grid = g;
+    }
+
+    debug!(rounds, first = part_one, total = part_two, "rolls removed");
+    (part_one, part_two)
+}
+
+fn threshold(params: &Params) -> Result<usize, ParamError> {
+    Ok(params.get("threshold")?.unwrap_or(THRESHOLD))
+}
+
+/// The [`Solution`] for day 4, as run by `aoc run --day 4`.
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    const TITLE: &'static str = "Printing Department";
+    const INPUT_SIZE: usize = 140;
+
+    type Input = Grid<bool>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn check(_: &str, _: &Self::Input, _: Option<u8>, params: &Params) -> Result<(), Error> {
+        threshold(params)?;
+        Ok(())
+    }
+
+    fn part_one(grid: &Self::Input, params: &Params) -> Self::PartOne {
+        remove(grid, threshold(params).expect("checked before solving")).0
+    }
+
+    fn part_two(grid: &Self::Input, params: &Params) -> Option<Self::PartTwo> {
+        Some(solve(grid, threshold(params).expect("checked before solving")).1)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::{Strategy, prop, prop_assert_eq, proptest};
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+";
+
+    #[test]
+    fn first_removal() {
+        let grid = parse_input(EXAMPLE).unwrap();
+        let (removed, grid) = remove(&grid, THRESHOLD);
+        assert_eq!(removed, 13);
+
+        let expected = parse_input(
+            "\
+.......@..
+.@@.@.@.@@
+@@@@@...@@
+@.@@@@..@.
+.@.@@@@.@.
+.@@@@@@@.@
+.@.@.@.@@@
+..@@@.@@@@
+.@@@@@@@@.
+....@@@...
+",
+        )
+        .unwrap();
+        assert_eq!(grid, expected);
+    }
+
+    #[test]
+    fn example() {
+        let grid = parse_input(EXAMPLE).unwrap();
+        assert_eq!(solve(&grid, THRESHOLD), (13, 43));
+    }
+
+    #[test]
+    fn crowded_rolls_stay() {
+        let grid = parse_input("@@@\n@@@\n@@@\n").unwrap();
+        // only the corners have fewer than four neighbours
+        assert_eq!(remove(&grid, THRESHOLD).0, 4);
+        assert_eq!(solve(&grid, THRESHOLD), (4, 9));
+    }
+
+    #[test]
+    fn lower_threshold() {
+        let grid = parse_input("@@@\n@@@\n@@@\n").unwrap();
+        // corners have three neighbours and edges five, but the middle eight
+        assert_eq!(remove(&grid, 4).0, 4);
+        assert_eq!(remove(&grid, 6).0, 8);
+        assert_eq!(remove(&grid, 0).0, 0);
+    }
+
+    #[test]
+    fn empty_grid() {
+        let grid = parse_input("...\n...\n").unwrap();
+        assert_eq!(solve(&grid, THRESHOLD), (0, 0));
+    }
+
+    #[test]
+    fn ragged_rows() {
+        let err = parse_input("@@.\n@.\n").unwrap_err();
+        assert_eq!(err.line(), 2);
+        assert_eq!(err.expected(), "a row 3 cells wide");
+    }
+
+    // a grid of up to six by six, as rows of rolls
+    fn small_grid() -> impl Strategy<Value = Vec<Vec<bool>>> {
+        (1..=6usize, 1..=6usize).prop_flat_map(|(width, height)| {
+            prop::collection::vec(
+                prop::collection::vec(prop::bool::weighted(0.65), width),
+                height,
+            )
+        })
+    }
+
+    // takes away one reachable roll at a time, counting the rolls around it
+    // afresh each time, until none can be
+    fn remove_one_by_one(mut rows: Vec<Vec<bool>>, threshold: usize) -> usize {
+        let around = |rows: &[Vec<bool>], r: usize, c: usize| {
+            let near = |i: usize, len: usize| i.saturating_sub(1)..(i + 2).min(len);
+            rows[near(r, rows.len())]
+                .iter()
+                .flat_map(|row| &row[near(c, row.len())])
+                .filter(|&&roll| roll)
+                .count()
+                - rows[r][c] as usize
+        };
+
+        let mut removed = 0;
+        'search: loop {
+            for r in 0..rows.len() {
+                for c in 0..rows[0].len() {
+                    if rows[r][c] && around(&rows, r, c) < threshold {
+                        rows[r][c] = false;
+                        removed += 1;
+                        continue 'search;
+                    }
+                }
+            }
+            return removed;
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_removing_one_by_one(rows in small_grid(), threshold in 0..=8usize) {
+            let input = rows
+                .iter()
+                .map(|row| row.iter().map(|&roll| if roll { '@' } else { '.' }).collect::<String>() + "\n")
+                .collect::<String>();
+            let grid = parse_input(&input).unwrap();
+            prop_assert_eq!(solve(&grid, threshold).1, remove_one_by_one(rows, threshold));
+        }
+    }
+}
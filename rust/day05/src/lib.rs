@@ -0,0 +1,262 @@
+//! Day 5: Cafeteria. Checking ingredient IDs against ranges of fresh IDs.
+
+use aoc_common::{Params, ParseError, Rng, Solution, parse_token};
+use tracing::{debug, trace};
+
+/// An inclusive range of fresh ingredient IDs.
+pub type IdRange = (u64, u64);
+
+/// The ingredient database.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Inventory {
+    /// The ranges of fresh IDs, which may overlap.
+    pub fresh: Vec<IdRange>,
+    /// The IDs of the ingredients on hand.
+    pub available: Vec<u64>,
+}
+
+/// Parses the fresh ranges, one per line like `3-5`, then a blank line and
+/// the available IDs, one per line.
+pub fn parse_input(input: &str) -> Result<Inventory, ParseError> {
+    let trimmed = input.trim();
+    let mut chunks = trimmed.split("\n\n");
+
+    let fresh = chunks
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .lines()
+        .map(|line| {
+            let (lower, upper) = line
+                .split_once('-')
+                .ok_or_else(|| ParseError::at(input, line, "a range like 3-5"))?;
+            Ok((
+                parse_token(input, lower, "a number")?,
+                parse_token(input, upper, "a number")?,
+            ))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let available = chunks
+        .next()
+        .ok_or_else(|| {
+            ParseError::at(
+                input,
+                &trimmed[trimmed.len()..],
+                "a blank line followed by the available IDs",
+            )
+        })?
+        .trim()
+        .lines()
+        .map(|line| parse_token(input, line, "an ingredient ID"))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Inventory { fresh, available })
+}
+
+/// Writes `size` fresh ranges, at least one, and five times as many
+/// available IDs, about half of which are fresh.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let ranges = (0..size)
+        .map(|_| {
+            let lower = rng.between(1, 500_000_000_000_000);
+            (lower, lower + rng.below(1_000_000_000_000))
+        })
+        .collect::<Vec<_>>();
+
+    let mut lines = ranges
+        .iter()
+        .map(|(lower, upper)| format!("{}-{}", lower, upper))
+        .collect::<Vec<_>>();
+    lines.push(String::new());
+    for _ in 0..(5 * size) {
+        let id = if !ranges.is_empty() && rng.chance(0.5) {
+            let &(lower, upper) = rng.pick(&ranges);
+            rng.between(lower, upper)
+        } else {
+            rng.between(1, 501_000_000_000_000)
+        };
+        lines.push(id.to_string());
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// Counts the IDs that fall in any of the ranges.
+pub fn solve_part_one(ranges: &[IdRange], ids: &[u64]) -> usize {
+    let fresh = ids
+        .iter()
+        .filter(|&&id| {
+            ranges
+                .iter()
+                .any(|&(lower, upper)| id >= lower && id <= upper)
+        })
+        .count();
+    debug!(ids = ids.len(), fresh, "IDs checked");
+    fresh
+}
+
+/// Sorts the ranges and merges any that overlap, so that every ID is covered
+/// by at most one range.
+pub fn merge_ranges(ranges: &[IdRange]) -> Vec<IdRange> {
+    let sorted_ranges = {
+        let mut sorted_ranges = ranges.to_vec();
+        sorted_ranges.sort_by_key(|rg| rg.0);
+        sorted_ranges
+    };
+
+    let mut merged_ranges = vec![];
+    let mut i = 0;
+    while i < sorted_ranges.len() {
+        let mut next = sorted_ranges[i];
+        i += 1;
+        while i < sorted_ranges.len() && sorted_ranges[i].0 <= next.1 {
+            next.1 = next.1.max(sorted_ranges[i].1);
+            i += 1;
+        }
+        trace!(lower = next.0, upper = next.1, "merged range");
+        merged_ranges.push(next);
+    }
+    debug!(
+        ranges = ranges.len(),
+        merged = merged_ranges.len(),
+        "ranges merged"
+    );
+    merged_ranges
+}
+
+/// Counts the distinct IDs covered by the ranges. That's one more than
+/// `u64` holds when they cover every ID.
+pub fn solve_part_two(ranges: &[IdRange]) -> u128 {
+    merge_ranges(ranges)
+        .iter()
+        .map(|range| (range.1 - range.0) as u128 + 1)
+        .sum()
+}
+
+/// The [`Solution`] for day 5, as run by `aoc run --day 5`.
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    const TITLE: &'static str = "Cafeteria";
+    const INPUT_SIZE: usize = 190;
+
+    type Input = Inventory;
+    type PartOne = usize;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(inventory: &Self::Input, _params: &Params) -> Self::PartOne {
+        solve_part_one(&inventory.fresh, &inventory.available)
+    }
+
+    fn part_two(inventory: &Self::Input, _params: &Params) -> Option<Self::PartTwo> {
+        Some(solve_part_two(&inventory.fresh))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use proptest::prelude::{Strategy, prop, prop_assert, prop_assert_eq, proptest};
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32
+";
+
+    #[test]
+    fn merging() {
+        let inventory = parse_input(EXAMPLE).unwrap();
+        assert_eq!(merge_ranges(&inventory.fresh), vec![(3, 5), (10, 20)]);
+    }
+
+    #[test]
+    fn merging_nested_and_touching() {
+        assert_eq!(merge_ranges(&[(1, 10), (2, 3), (4, 5)]), vec![(1, 10)]);
+        assert_eq!(merge_ranges(&[(5, 7), (1, 5)]), vec![(1, 7)]);
+        assert_eq!(merge_ranges(&[(1, 1), (2, 2)]), vec![(1, 1), (2, 2)]);
+        assert_eq!(merge_ranges(&[]), vec![]);
+    }
+
+    #[test]
+    fn example() {
+        let inventory = parse_input(EXAMPLE).unwrap();
+        assert_eq!(solve_part_one(&inventory.fresh, &inventory.available), 3);
+        assert_eq!(solve_part_two(&inventory.fresh), 14);
+    }
+
+    #[test]
+    fn every_id() {
+        assert_eq!(solve_part_two(&[(0, u64::MAX)]), 1 << 64);
+        assert_eq!(solve_part_two(&[(0, 10), (11, u64::MAX)]), 1 << 64);
+    }
+
+    #[test]
+    fn ids_on_the_edges_are_fresh() {
+        assert_eq!(solve_part_one(&[(3, 5)], &[2, 3, 5, 6]), 2);
+    }
+
+    #[test]
+    fn missing_ids() {
+        let err = parse_input("3-5\n10-14\n").unwrap_err();
+        assert_eq!(err.expected(), "a blank line followed by the available IDs");
+    }
+
+    fn small_ranges() -> impl Strategy<Value = Vec<IdRange>> {
+        prop::collection::vec(
+            (0..60u64, 0..15u64).prop_map(|(lo, len)| (lo, lo + len)),
+            0..12,
+        )
+    }
+
+    // every ID in any of the ranges, one at a time
+    fn id_set(ranges: &[IdRange]) -> BTreeSet<u64> {
+        ranges.iter().flat_map(|&(lo, hi)| lo..=hi).collect()
+    }
+
+    proptest! {
+        #[test]
+        fn merging_agrees_with_a_set(ranges in small_ranges()) {
+            let merged = merge_ranges(&ranges);
+            prop_assert_eq!(id_set(&merged), id_set(&ranges));
+            prop_assert_eq!(solve_part_two(&ranges), id_set(&ranges).len() as u128);
+
+            // sorted, and no ID in two of them
+            for pair in merged.windows(2) {
+                prop_assert!(pair[0].1 < pair[1].0, "{:?} overlaps", pair);
+            }
+        }
+
+        #[test]
+        fn fresh_ids_agree_with_a_set(
+            ranges in small_ranges(),
+            ids in prop::collection::vec(0..80u64, 0..20),
+        ) {
+            let fresh = id_set(&ranges);
+            let expected = ids.iter().filter(|id| fresh.contains(id)).count();
+            prop_assert_eq!(solve_part_one(&ranges, &ids), expected);
+        }
+    }
+}
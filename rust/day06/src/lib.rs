@@ -0,0 +1,299 @@
+//! Day 6: Trash Compactor. Reading a worksheet of arithmetic problems, by
+//! row and then the cephalopod way, by column.
+
+use std::iter;
+use std::ops::Range;
+
+use aoc_common::{Answer, Grid, Params, ParseError, Rng, Solution, parse_token};
+use tracing::{debug, trace};
+
+/// The operator at the bottom of a problem.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Op {
+    Add,
+    Mul,
+}
+
+impl Op {
+    /// Adds or multiplies all the numbers together, which for a product of
+    /// many large numbers can go past a `u64`.
+    pub fn apply(&self, nums: &[u64]) -> Answer {
+        match self {
+            Op::Add => nums.iter().copied().sum(),
+            Op::Mul => nums.iter().copied().product(),
+        }
+    }
+}
+
+/// One problem on the worksheet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Problem {
+    pub op: Op,
+    /// The numbers read a row at a time, top to bottom.
+    pub rows: Vec<u64>,
+    /// The numbers read cephalopod style, a column at a time from right to
+    /// left with the most significant digit at the top.
+    pub columns: Vec<u64>,
+}
+
+// a line of the worksheet with the byte offset of each character, and of its
+// end, so slicing by character columns doesn't walk the line every time
+struct Line<'a> {
+    text: &'a str,
+    offsets: Vec<usize>,
+}
+
+impl<'a> Line<'a> {
+    fn new(text: &'a str) -> Self {
+        let offsets = text
+            .char_indices()
+            .map(|(i, _)| i)
+            .chain(iter::once(text.len()))
+            .collect();
+        Line { text, offsets }
+    }
+
+    // the part of the line covering the character columns in cols, clamped to
+    // the line
+    fn columns(&self, cols: Range<usize>) -> &'a str {
+        let byte = |col: usize| self.offsets[col.min(self.offsets.len() - 1)];
+        &self.text[byte(cols.start)..byte(cols.end)]
+    }
+}
+
+/// Parses the worksheet. Problems are separated by columns of spaces, and the
+/// operator sits at the left of the bottom row of each.
+pub fn parse_input(input: &str) -> Result<Vec<Problem>, ParseError> {
+    let lines = input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(Line::new)
+        .collect::<Vec<_>>();
+
+    // editors like to strip trailing spaces, so treat short rows as padded
+    let grid = Grid::from_rows(
+        lines
+            .iter()
+            .map(|line| line.text.chars().collect())
+            .collect(),
+        ' ',
+    );
+
+    let mut problems = vec![];
+    let num_rows = grid.height();
+    if num_rows == 0 {
+        return Err(ParseError::at(input, input, "a row of operators"));
+    }
+    let num_cols = grid.width();
+
+    // the cephalopods read down the columns, which are rows once transposed
+    let columns_grid = grid.transpose();
+
+    let mut c = 0;
+    while c < num_cols {
+        // the operator positions define the column
+        let c_init = c;
+        let op = match grid[(num_rows - 1, c)] {
+            '+' => Op::Add,
+            '*' => Op::Mul,
+            _ => {
+                return Err(ParseError::at(
+                    input,
+                    lines[num_rows - 1].columns(c..(c + 1)),
+                    "an operator, + or *",
+                ));
+            }
+        };
+
+        c += 1;
+        while c < num_cols && grid[(num_rows - 1, c)] == ' ' {
+            c += 1;
+        }
+
+        let normal_nums = (0..(num_rows - 1))
+            .map(|r| parse_token(input, lines[r].columns(c_init..c).trim(), "a number"))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let cephalopod_nums = (c_init..c)
+            .rev()
+            .filter_map(|cc| {
+                let s = columns_grid.row(cc)[..(num_rows - 1)]
+                    .iter()
+                    .collect::<String>();
+                if s.trim().is_empty() {
+                    return None;
+                }
+                Some(s.trim().parse::<u64>().map_err(|_| {
+                    // point at the first character in the column that breaks the number
+                    let first = s.chars().take_while(|ch| ch.is_whitespace()).count();
+                    let r = (first..(num_rows - 1))
+                        .find(|&r| !grid[(r, cc)].is_ascii_digit())
+                        .unwrap_or(first);
+                    ParseError::at(input, lines[r].columns(cc..(cc + 1)), "a digit")
+                }))
+            })
+            .collect::<Result<Vec<u64>, _>>()?;
+        problems.push(Problem {
+            op,
+            rows: normal_nums,
+            columns: cephalopod_nums,
+        })
+    }
+
+    Ok(problems)
+}
+
+/// Writes `size` problems of four numbers each, at least one, with up to
+/// four digits. The numbers in each problem are lined up on the left or on
+/// the right.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let num_rows = 4;
+    let mut rows = vec![String::new(); num_rows + 1];
+
+    for p in 0..size.max(1) {
+        if p > 0 {
+            for row in rows.iter_mut() {
+                row.push(' ');
+            }
+        }
+
+        // shorter numbers all come first or all come last, so that no column
+        // has a gap in the middle of a number
+        let mut nums = (0..num_rows)
+            .map(|_| rng.between(1, 9999).to_string())
+            .collect::<Vec<_>>();
+        nums.sort_by_key(|num| num.len());
+        if rng.chance(0.5) {
+            nums.reverse();
+        }
+        let width = nums.iter().map(|num| num.len()).max().unwrap_or_default();
+        let left = rng.chance(0.5);
+        for (row, num) in rows.iter_mut().zip(nums.iter()) {
+            if left {
+                row.push_str(&format!("{:<width$}", num));
+            } else {
+                row.push_str(&format!("{:>width$}", num));
+            }
+        }
+
+        let op = if rng.chance(0.5) { "+" } else { "*" };
+        rows[num_rows].push_str(&format!("{:<width$}", op));
+    }
+
+    rows.join("\n") + "\n"
+}
+
+/// Returns the grand totals of the worksheet read by row and read by column.
+pub fn solve(problems: &[Problem]) -> (Answer, Answer) {
+    let (by_row, by_column) =
+        problems
+            .iter()
+            .fold(Default::default(), |(by_row, by_column), problem| {
+                let (row, column) = (
+                    problem.op.apply(&problem.rows),
+                    problem.op.apply(&problem.columns),
+                );
+                trace!(op = ?problem.op, %row, %column, "problem");
+                (by_row + row, by_column + column)
+            });
+    debug!(problems = problems.len(), %by_row, %by_column, "worksheet totalled");
+    (by_row, by_column)
+}
+
+/// The [`Solution`] for day 6, as run by `aoc run --day 6`.
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    const TITLE: &'static str = "Trash Compactor";
+    const INPUT_SIZE: usize = 1000;
+
+    type Input = Vec<Problem>;
+    type PartOne = Answer;
+    type PartTwo = Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(problems: &Self::Input, _params: &Params) -> Self::PartOne {
+        solve(problems).0
+    }
+
+    fn part_two(problems: &Self::Input, _params: &Params) -> Option<Self::PartTwo> {
+        Some(solve(problems).1)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  \n";
+
+    #[test]
+    fn cephalopod_columns() {
+        let problems = parse_input(EXAMPLE).unwrap();
+        assert_eq!(problems.len(), 4);
+
+        assert_eq!(
+            problems[0],
+            Problem {
+                op: Op::Mul,
+                rows: vec![123, 45, 6],
+                columns: vec![356, 24, 1],
+            }
+        );
+        assert_eq!(
+            problems[3],
+            Problem {
+                op: Op::Add,
+                rows: vec![64, 23, 314],
+                columns: vec![4, 431, 623],
+            }
+        );
+    }
+
+    #[test]
+    fn stripped_trailing_spaces() {
+        let stripped = EXAMPLE
+            .lines()
+            .map(|line| line.trim_end())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let problems = parse_input(&stripped).unwrap();
+        assert_eq!(problems[3].columns, vec![4, 431, 623]);
+    }
+
+    #[test]
+    fn example() {
+        let problems = parse_input(EXAMPLE).unwrap();
+        assert_eq!(
+            solve(&problems),
+            (Answer::from(4277556u64), Answer::from(3263827u64))
+        );
+    }
+
+    #[test]
+    fn products_past_u64() {
+        assert_eq!(
+            Op::Mul.apply(&[u64::MAX, 2]).to_string(),
+            "36893488147419103230"
+        );
+        assert_eq!(
+            Op::Add.apply(&[u64::MAX, 1]).to_string(),
+            "18446744073709551616"
+        );
+    }
+
+    #[test]
+    fn bad_operator() {
+        let err = parse_input("1 2\n- +\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 1));
+        assert_eq!(err.found(), "-");
+    }
+}
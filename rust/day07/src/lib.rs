@@ -0,0 +1,296 @@
+//! Day 7: Laboratories. Following a tachyon beam as splitters divide it.
+
+use aoc_common::{Answer, Grid, Params, ParseError, Rng, Solution};
+use tracing::{debug, trace};
+
+/// A tachyon manifold: where the beam enters, and the splitters it meets on
+/// the way down.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Manifold {
+    start: usize,
+    splitters: Grid<bool>,
+}
+
+/// Parses a diagram with the start, `S`, on the first row, and splitters,
+/// `^`, below it.
+pub fn parse_input(input: &str) -> Result<Manifold, ParseError> {
+    let mut lines = input.lines().filter(|line| !line.trim().is_empty());
+
+    let first = lines.next().unwrap_or(input);
+    let start = first
+        .find('S')
+        .ok_or_else(|| ParseError::at(input, first, "a row with the start position, S"))?;
+    let start = first[..start].chars().count();
+
+    let splitters = Grid::parse_lines(
+        input,
+        lines,
+        |c| match c {
+            '^' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        },
+        "a splitter, ^, or empty space, .",
+    )?;
+
+    Ok(Manifold { start, splitters })
+}
+
+/// Writes a manifold with `size` rows of splitters, each on a spot the beam
+/// can reach, with an empty row between each.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    // wide enough that the beam never leaves the manifold
+    let width = 2 * size + 3;
+    let start = size + 1;
+
+    let mut rows = vec![];
+    let mut row = vec!['.'; width];
+    row[start] = 'S';
+    rows.push(row);
+
+    for depth in 0..size {
+        rows.push(vec!['.'; width]);
+        let mut row = vec!['.'; width];
+        for c in ((start - depth)..=(start + depth)).step_by(2) {
+            if rng.chance(0.75) {
+                row[c] = '^';
+            }
+        }
+        rows.push(row);
+    }
+    rows.push(vec!['.'; width]);
+
+    rows.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+impl Manifold {
+    /// Creates a manifold whose beam enters at column `start`, above a grid
+    /// that is true where there are splitters.
+    pub fn new(start: usize, splitters: Grid<bool>) -> Self {
+        Manifold { start, splitters }
+    }
+
+    /// Returns how many times the beam is split, and how many timelines a
+    /// single particle ends up in. The timelines can double at every row of
+    /// splitters, so past 64 rows or so they need a big integer.
+    pub fn solve(&self) -> (u64, Answer) {
+        // timelines by column, shifted one to the right so that beams split
+        // off either edge of the manifold have somewhere to go
+        let width = self.splitters.width().max(self.start + 1) + 2;
+        let mut part_one = 0;
+        let mut timelines = vec![Answer::default(); width];
+        timelines[self.start + 1] = Answer::from(1u64);
+
+        for (r, row) in self.splitters.rows().enumerate() {
+            let mut new_timelines = vec![Answer::default(); width];
+
+            for (pos, timeline_count) in timelines.into_iter().enumerate() {
+                if timeline_count == Answer::Unsigned(0) {
+                    continue;
+                }
+                if pos >= 1 && row.get(pos - 1) == Some(&true) {
+                    part_one += 1;
+                    new_timelines[pos - 1] += timeline_count.clone();
+                    new_timelines[pos + 1] += timeline_count;
+                } else {
+                    new_timelines[pos] += timeline_count;
+                }
+            }
+            timelines = new_timelines;
+            trace!(
+                row = r,
+                beams = timelines
+                    .iter()
+                    .filter(|&t| *t != Answer::Unsigned(0))
+                    .count(),
+                splits = part_one,
+                "row passed"
+            );
+        }
+
+        let total_timelines = timelines.into_iter().sum::<Answer>();
+        debug!(splits = part_one, timelines = %total_timelines, "manifold passed");
+        (part_one, total_timelines)
+    }
+}
+
+/// The [`Solution`] for day 7, as run by `aoc run --day 7`.
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    const TITLE: &'static str = "Laboratories";
+    const INPUT_SIZE: usize = 70;
+
+    type Input = Manifold;
+    type PartOne = u64;
+    type PartTwo = Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(manifold: &Self::Input, _params: &Params) -> Self::PartOne {
+        manifold.solve().0
+    }
+
+    fn part_two(manifold: &Self::Input, _params: &Params) -> Option<Self::PartTwo> {
+        Some(manifold.solve().1)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use proptest::prelude::{Strategy, prop, prop_assert_eq, proptest};
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............
+";
+
+    #[test]
+    fn example() {
+        let manifold = parse_input(EXAMPLE).unwrap();
+        assert_eq!(manifold.solve(), (21, Answer::from(40u64)));
+    }
+
+    #[test]
+    fn no_splitters() {
+        let manifold = parse_input("..S..\n.....\n.....\n").unwrap();
+        assert_eq!(manifold.solve(), (0, Answer::from(1u64)));
+    }
+
+    #[test]
+    fn beams_merge() {
+        // both halves of the first split hit splitters, and the middle beams
+        // join up again
+        let manifold = parse_input("..S..\n..^..\n.^.^.\n").unwrap();
+        assert_eq!(manifold.solve(), (3, Answer::from(4u64)));
+    }
+
+    #[test]
+    fn missed_splitters_are_not_counted() {
+        let manifold = parse_input("..S..\n^...^\n").unwrap();
+        assert_eq!(manifold.solve(), (0, Answer::from(1u64)));
+    }
+
+    #[test]
+    fn splits_at_the_edge() {
+        // half of each beam leaves the manifold, but is still a timeline
+        let manifold = parse_input("S.S\n^..\n..^\n").unwrap();
+        assert_eq!(manifold.solve(), (1, Answer::from(2u64)));
+        let manifold = parse_input("..S\n..^\n").unwrap();
+        assert_eq!(manifold.solve(), (1, Answer::from(2u64)));
+    }
+
+    #[test]
+    fn timelines_past_u64() {
+        // a full triangle of splitters doubles the timelines at every row
+        let rows = 70usize;
+        let width = 2 * rows + 3;
+        let start = rows + 1;
+
+        let mut input = String::new();
+        for r in 0..=rows {
+            for c in 0..width {
+                // row r has splitters at every other column within r - 1 of
+                // the start, the same spots as the generator uses
+                let offset = c.abs_diff(start);
+                input.push(if r == 0 && c == start {
+                    'S'
+                } else if r > 0 && offset < r && (offset + r - 1) % 2 == 0 {
+                    '^'
+                } else {
+                    '.'
+                });
+            }
+            input.push('\n');
+        }
+
+        let (_, timelines) = parse_input(&input).unwrap().solve();
+        assert_eq!(timelines, Answer::from(1u128 << rows));
+    }
+
+    #[test]
+    fn missing_start() {
+        let err = parse_input(".....\n..^..\n").unwrap_err();
+        assert_eq!(err.expected(), "a row with the start position, S");
+    }
+
+    // a diagram up to six wide with up to six rows of splitters
+    fn small_manifold() -> impl Strategy<Value = String> {
+        (1..=6usize, 1..=6usize)
+            .prop_flat_map(|(width, height)| {
+                (
+                    0..width,
+                    prop::collection::vec(
+                        prop::collection::vec(prop::bool::weighted(0.4), width),
+                        height,
+                    ),
+                )
+            })
+            .prop_map(|(start, rows)| {
+                let width = rows[0].len();
+                let mut input =
+                    format!("{}S{}\n", ".".repeat(start), ".".repeat(width - start - 1));
+                for row in rows.iter() {
+                    input.extend(row.iter().map(|&splitter| if splitter { '^' } else { '.' }));
+                    input.push('\n');
+                }
+                input
+            })
+    }
+
+    // sends a particle down every way it can go, one at a time, noting the
+    // splitters it hits and counting where it comes out
+    fn follow(rows: &[Vec<bool>], r: usize, c: i64, hit: &mut HashSet<(usize, i64)>) -> u64 {
+        let Some(row) = rows.get(r) else {
+            return 1;
+        };
+        if c >= 0 && row.get(c as usize) == Some(&true) {
+            hit.insert((r, c));
+            follow(rows, r + 1, c - 1, hit) + follow(rows, r + 1, c + 1, hit)
+        } else {
+            follow(rows, r + 1, c, hit)
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_following_every_particle(input in small_manifold()) {
+            let manifold = parse_input(&input).unwrap();
+            let rows = input
+                .lines()
+                .skip(1)
+                .map(|line| line.chars().map(|c| c == '^').collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            let mut hit = HashSet::new();
+            let timelines = follow(&rows, 0, manifold.start as i64, &mut hit);
+            prop_assert_eq!(manifold.solve(), (hit.len() as u64, Answer::from(timelines)));
+        }
+    }
+}
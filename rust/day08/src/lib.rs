@@ -0,0 +1,314 @@
+//! Day 8: Playground. Stringing junction boxes together into circuits,
+//! closest pairs first.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashSet};
+
+use aoc_common::{Error, ParamError, Params, ParseError, Rng, Solution, parse_token};
+use tracing::{debug, trace};
+
+/// The position of a junction box in 3D.
+pub type Junction = [i64; 3];
+
+/// The number of closest pairs to connect for part one in the real puzzle.
+/// The example uses 10, which can be given with `--pairs 10`.
+pub const PAIRS: usize = 1000;
+
+/// The furthest a junction box can be from zero along each axis, so that
+/// the squared distance between two boxes fits in a `u128`.
+pub const MAX_COORD: i64 = i64::MAX / 2;
+
+#[derive(Eq, PartialEq)]
+struct RelativeVector {
+    i: usize,
+    j: usize,
+    dist_sq: u128,
+}
+
+impl Ord for RelativeVector {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.dist_sq.cmp(&self.dist_sq)
+    }
+}
+
+impl PartialOrd for RelativeVector {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Parses one junction box per line, like `162,817,812`.
+pub fn parse_input(input: &str) -> Result<Vec<Junction>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let junction = line
+                .split(',')
+                .map(|pc| {
+                    let expected = format!("a coordinate from -{} to {}", MAX_COORD, MAX_COORD);
+                    parse_token(input, pc, &expected)
+                        .ok()
+                        .filter(|coord| (-MAX_COORD..=MAX_COORD).contains(coord))
+                        .ok_or_else(|| ParseError::at(input, pc, expected))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            junction
+                .try_into()
+                .map_err(|_| ParseError::at(input, line, "three coordinates like 162,817,812"))
+        })
+        .collect::<Result<Vec<_>, _>>()
+}
+
+/// Writes `size` junction boxes, at least two, scattered through a cube
+/// 100,000 on a side.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(2))
+        .map(|_| {
+            let [x, y, z] = [(); 3].map(|_| rng.below(100_000));
+            format!("{},{},{}\n", x, y, z)
+        })
+        .collect()
+}
+
+/// Connects the closest `pairs` pairs of junction boxes and multiplies the
+/// sizes of the three largest circuits, then keeps connecting until there
+/// is one circuit and multiplies the X coordinates of the last pair joined.
+///
+/// If every box is in one circuit before `pairs` pairs have been connected,
+/// that circuit is the largest for part one.
+///
+/// # Panics
+///
+/// If there are fewer than two junction boxes.
+pub fn solve(junctions: &[Junction], pairs: usize) -> (i64, i128) {
+    let mut distances = BinaryHeap::<RelativeVector>::new();
+
+    for i in 0..junctions.len() {
+        for j in (i + 1)..junctions.len() {
+            let dist_sq = (0..3)
+                .map(|d| {
+                    let delta = junctions[i][d].abs_diff(junctions[j][d]) as u128;
+                    delta * delta
+                })
+                .sum::<u128>();
+
+            let rel_vec = RelativeVector { i, j, dist_sq };
+            distances.push(rel_vec)
+        }
+    }
+    debug!(pairs = distances.len(), "distances queued");
+
+    let mut contained_in = (0..junctions.len()).collect::<Vec<_>>();
+    let mut subsets: Vec<Vec<usize>> = (0..junctions.len()).map(|i| vec![i]).collect::<Vec<_>>();
+
+    let mut part_one: Option<i64> = None;
+
+    let mut edge_count = 0;
+    while let Some(rel_vec) = distances.pop() {
+        if edge_count == pairs {
+            let unique_subsets = contained_in.iter().cloned().collect::<HashSet<_>>();
+            let mut counts = unique_subsets
+                .iter()
+                .map(|set_idx| subsets[*set_idx].len() as i64)
+                .collect::<Vec<_>>();
+            counts.sort();
+            counts.reverse();
+            part_one = Some(counts.iter().take(3).product());
+            debug!(circuits = unique_subsets.len(), largest = ?&counts[..counts.len().min(3)], "connected {} pairs", pairs);
+        }
+
+        edge_count += 1;
+
+        let i = rel_vec.i;
+        let j = rel_vec.j;
+
+        let joined = contained_in[i] != contained_in[j];
+        trace!(
+            pop = edge_count,
+            i,
+            j,
+            dist_sq = rel_vec.dist_sq,
+            joined,
+            "popped pair"
+        );
+        if !joined {
+            continue;
+        }
+
+        let j_set_idx = contained_in[j];
+        let j_set = subsets[j_set_idx].clone();
+
+        let i_set_idx = contained_in[i];
+
+        for &k in j_set.iter() {
+            contained_in[k] = i_set_idx;
+        }
+
+        let i_set = subsets.get_mut(i_set_idx).unwrap();
+
+        i_set.extend(j_set);
+
+        if i_set.len() == junctions.len() {
+            let part_two = junctions[i][0] as i128 * junctions[j][0] as i128;
+            debug!(
+                pops = edge_count,
+                remaining = distances.len(),
+                "one circuit"
+            );
+            return (part_one.unwrap_or(junctions.len() as i64), part_two);
+        }
+    }
+
+    unreachable!("connecting every pair leaves one circuit")
+}
+
+fn pairs(params: &Params) -> Result<usize, ParamError> {
+    Ok(params.get("pairs")?.unwrap_or(PAIRS))
+}
+
+/// The [`Solution`] for day 8, as run by `aoc run --day 8`.
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    const TITLE: &'static str = "Playground";
+    const INPUT_SIZE: usize = 1000;
+
+    type Input = Vec<Junction>;
+    type PartOne = i64;
+    type PartTwo = i128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    /// Neither part can be solved without a pair of junction boxes, and
+    /// part one can't connect more pairs than there are.
+    fn check(
+        text: &str,
+        junctions: &Self::Input,
+        part: Option<u8>,
+        params: &Params,
+    ) -> Result<(), Error> {
+        if junctions.len() < 2 {
+            return Err(ParseError::at(text, text.trim(), "at least two junction boxes").into());
+        }
+
+        let pairs = pairs(params)?;
+        let available = junctions.len() * junctions.len().saturating_sub(1) / 2;
+        if part.is_none_or(|part| part == 1) && pairs > available {
+            return Err(ParamError::new(
+                "pairs",
+                format!(
+                    "can't connect {} pairs of {} junction boxes, only {}",
+                    pairs,
+                    junctions.len(),
+                    available
+                ),
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    fn part_one(junctions: &Self::Input, params: &Params) -> Self::PartOne {
+        solve(junctions, pairs(params).expect("checked before solving")).0
+    }
+
+    fn part_two(junctions: &Self::Input, params: &Params) -> Option<Self::PartTwo> {
+        Some(solve(junctions, pairs(params).expect("checked before solving")).1)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689
+";
+
+    #[test]
+    fn example() {
+        let junctions = parse_input(EXAMPLE).unwrap();
+        assert_eq!(solve(&junctions, 10), (40, 25272));
+    }
+
+    #[test]
+    fn pairs_param() {
+        let params = |pairs: &str| Params::new([(String::from("pairs"), String::from(pairs))]);
+        assert_eq!(pairs(&Params::default()), Ok(PAIRS));
+        assert_eq!(pairs(&params("10")), Ok(10));
+        assert!(pairs(&params("abc")).is_err());
+
+        let junctions = parse_input(EXAMPLE).unwrap();
+        assert!(Day08::check(EXAMPLE, &junctions, None, &params("190")).is_ok());
+        assert!(Day08::check(EXAMPLE, &junctions, None, &params("191")).is_err());
+        assert!(Day08::check(EXAMPLE, &junctions, Some(2), &params("191")).is_ok());
+    }
+
+    #[test]
+    fn too_few_junctions() {
+        for (input, pairs) in [("", "0"), ("1,2,3\n", "0")] {
+            let junctions = parse_input(input).unwrap();
+            let params = Params::new([(String::from("pairs"), String::from(pairs))]);
+            for part in [None, Some(1), Some(2)] {
+                assert!(matches!(
+                    Day08::check(input, &junctions, part, &params),
+                    Err(Error::Parse(_))
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn line_of_junctions() {
+        let junctions = vec![[0, 0, 0], [1, 0, 0], [10, 0, 0]];
+        assert_eq!(solve(&junctions, 1), (2, 10));
+        assert_eq!(solve(&junctions, 0), (1, 10));
+        // one circuit after two pairs, which the third doesn't change
+        assert_eq!(solve(&junctions, 3), (3, 10));
+    }
+
+    #[test]
+    fn far_apart() {
+        // distances and X coordinates whose squares are beyond 64 bits
+        let far = 1i64 << 32;
+        let junctions = vec![[far, 0, 0], [-far, 0, 0], [far, 3 * far, 0]];
+        assert_eq!(solve(&junctions, 1), (2, 1i128 << 64));
+
+        let junctions = vec![[MAX_COORD; 3], [-MAX_COORD; 3]];
+        assert_eq!(solve(&junctions, 1), (2, -(MAX_COORD as i128).pow(2)));
+        assert!(parse_input(&format!("0,0,{}\n", MAX_COORD + 1)).is_err());
+    }
+
+    #[test]
+    fn two_coordinates() {
+        let err = parse_input("1,2,3\n4,5\n").unwrap_err();
+        assert_eq!(err.line(), 2);
+    }
+}
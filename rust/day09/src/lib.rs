@@ -0,0 +1,471 @@
+//! Day 9: Movie Theater. Finding the largest rectangles with red tiles in
+//! opposite corners.
+
+use aoc_common::{Params, ParseError, Rng, Solution, parse_token};
+use tracing::{debug, debug_span, trace};
+
+/// The position of a red tile, as (x, y).
+pub type Location = (i64, i64);
+
+/// The furthest a red tile can be from zero either way, so that the floor
+/// can double its coordinates without overflowing.
+pub const MAX_COORD: i64 = i64::MAX / 2;
+
+fn coordinate(input: &str, token: &str) -> Result<i64, ParseError> {
+    let expected = format!("a coordinate from -{} to {}", MAX_COORD, MAX_COORD);
+    parse_token(input, token, &expected)
+        .ok()
+        .filter(|coord| (-MAX_COORD..=MAX_COORD).contains(coord))
+        .ok_or_else(|| ParseError::at(input, token, expected))
+}
+
+/// Parses one red tile per line, like `7,1`, in order around the loop they
+/// make.
+pub fn parse_input(input: &str) -> Result<Vec<Location>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let (x, y) = line
+                .split_once(',')
+                .ok_or_else(|| ParseError::at(input, line, "a location like 7,1"))?;
+            Ok((coordinate(input, x)?, coordinate(input, y)?))
+        })
+        .collect::<Result<Vec<_>, _>>()
+}
+
+/// Writes a loop of about `size` red tiles. The loop is a row of columns
+/// with ragged tops and bottoms, so it has plenty of notches to trip up
+/// rectangles.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const SCALE: u64 = 100_000;
+    let num_cols = (size / 4).max(1);
+    let mid = SCALE / 2;
+
+    let mut xs = vec![rng.between(1, 1000)];
+    for _ in 0..num_cols {
+        let gap = rng.between(1, (2 * SCALE / num_cols as u64).max(1));
+        xs.push(xs[xs.len() - 1] + gap);
+    }
+
+    // every top is above the middle and every bottom below it, so
+    // neighbouring columns always overlap. neighbours never share a height,
+    // or the corner between them wouldn't be a corner
+    let mut heights = |lo, hi| {
+        let mut heights = Vec::<u64>::new();
+        while heights.len() < num_cols {
+            let h = rng.between(lo, hi);
+            if heights.last() != Some(&h) {
+                heights.push(h);
+            }
+        }
+        heights
+    };
+    let tops = heights(mid + 1, SCALE);
+    let bottoms = heights(1, mid - 1);
+
+    let mut locs = vec![];
+    for i in 0..num_cols {
+        locs.push((xs[i], tops[i]));
+        locs.push((xs[i + 1], tops[i]));
+    }
+    for i in (0..num_cols).rev() {
+        locs.push((xs[i + 1], bottoms[i]));
+        locs.push((xs[i], bottoms[i]));
+    }
+
+    locs.iter().map(|(x, y)| format!("{},{}\n", x, y)).collect()
+}
+
+/// The tiles inside the loop of red and green tiles, kept on a grid
+/// squashed down to the columns and rows with red tiles in them and the gaps
+/// between each pair of those, since every tile in a gap is the same as the
+/// others there. Assumes the loop only runs horizontally and vertically.
+#[derive(Clone, Debug)]
+pub struct Floor {
+    xs: Vec<i64>,
+    ys: Vec<i64>,
+    // outside[r][c] counts the squashed cells outside the loop above and to
+    // the left of (r, c), exclusive
+    outside: Vec<Vec<u32>>,
+}
+
+// the squashed index of each column or row with red tiles, with the gap
+// after it at the odd index in between
+fn squashed(coords: &[i64], coord: i64) -> Option<usize> {
+    match coords.binary_search(&coord) {
+        Ok(i) => Some(2 * i),
+        Err(i) if i > 0 && i < coords.len() => Some(2 * i - 1),
+        Err(_) => None,
+    }
+}
+
+impl Floor {
+    /// Fills in the loop through the red tiles, given in order around it.
+    pub fn new(locs: &[Location]) -> Self {
+        let distinct = |coord: fn(&Location) -> i64| {
+            let mut coords = locs.iter().map(coord).collect::<Vec<_>>();
+            coords.sort();
+            coords.dedup();
+            coords
+        };
+        let xs = distinct(|loc| loc.0);
+        let ys = distinct(|loc| loc.1);
+
+        // doubled coordinates, so that the gap after a column or row lands
+        // on a whole number
+        let at = |coords: &[i64], i: usize| 2 * coords[i / 2] + (i % 2) as i64;
+        // a gap between neighbouring columns or rows has no tiles to be
+        // outside the loop
+        let empty = |coords: &[i64], i: usize| i % 2 == 1 && coords[i / 2 + 1] == coords[i / 2] + 1;
+        let num_cols = (2 * xs.len()).saturating_sub(1);
+        let num_rows = (2 * ys.len()).saturating_sub(1);
+        let edges = (0..locs.len())
+            .map(|k| {
+                let (a, b) = (locs[k], locs[(k + 1) % locs.len()]);
+                ((2 * a.0, 2 * a.1), (2 * b.0, 2 * b.1))
+            })
+            .collect::<Vec<_>>();
+
+        let mut outside = vec![vec![0; num_cols + 1]; num_rows + 1];
+        for r in 0..num_rows {
+            let y = at(&ys, r);
+
+            // scan the row from the left, flipping between outside and
+            // inside at each vertical edge. an edge takes in its lower end
+            // but not its upper one, so a row through a corner sees it once
+            let mut crossings = vec![];
+            let mut on_edge = vec![false; num_cols];
+            for &(a, b) in edges.iter() {
+                let (lo, hi) = (a.1.min(b.1), a.1.max(b.1));
+                let cols = if a.0 == b.0 && lo <= y && y <= hi {
+                    if y < hi {
+                        crossings.push(a.0);
+                    }
+                    a.0..=a.0
+                } else if a.1 == y {
+                    a.0.min(b.0)..=a.0.max(b.0)
+                } else {
+                    continue;
+                };
+                // edges run between red tiles, so their ends are all columns
+                let first = squashed(&xs, cols.start() / 2).unwrap();
+                let last = squashed(&xs, cols.end() / 2).unwrap();
+                on_edge[first..=last].fill(true);
+            }
+            crossings.sort();
+
+            let mut crossed = 0;
+            for c in 0..num_cols {
+                let x = at(&xs, c);
+                while crossed < crossings.len() && crossings[crossed] < x {
+                    crossed += 1;
+                }
+                let inside = crossed % 2 == 1 || on_edge[c] || empty(&xs, c) || empty(&ys, r);
+
+                outside[r + 1][c + 1] =
+                    outside[r][c + 1] + outside[r + 1][c] - outside[r][c] + u32::from(!inside);
+            }
+        }
+
+        debug!(
+            tiles = locs.len(),
+            cols = num_cols,
+            rows = num_rows,
+            "floor squashed"
+        );
+        Floor { xs, ys, outside }
+    }
+
+    /// Whether a tile is inside the loop or on it.
+    pub fn contains(&self, tile: &Location) -> bool {
+        self.covers(tile, tile)
+    }
+
+    /// Whether every tile of the rectangle with corners `a` and `b` is
+    /// inside the loop or on it.
+    pub fn covers(&self, a: &Location, b: &Location) -> bool {
+        let cols = (
+            squashed(&self.xs, a.0.min(b.0)),
+            squashed(&self.xs, a.0.max(b.0)),
+        );
+        let rows = (
+            squashed(&self.ys, a.1.min(b.1)),
+            squashed(&self.ys, a.1.max(b.1)),
+        );
+        let ((Some(c0), Some(c1)), (Some(r0), Some(r1))) = (cols, rows) else {
+            return false;
+        };
+
+        let outside = &self.outside;
+        outside[r1 + 1][c1 + 1] + outside[r0][c0] - outside[r0][c1 + 1] - outside[r1 + 1][c0] == 0
+    }
+}
+
+// the sides fit in 64 bits for tiles within MAX_COORD of zero, so the area
+// fits in 128
+fn area(loc_one: &Location, loc_two: &Location) -> u128 {
+    let side = |a: i64, b: i64| a.abs_diff(b) as u128 + 1;
+    side(loc_one.0, loc_two.0) * side(loc_one.1, loc_two.1)
+}
+
+/// The area of the largest rectangle with red tiles in opposite corners.
+pub fn solve_part_one(locs: &[Location]) -> u128 {
+    let mut part_one = 0;
+
+    for i in 0..locs.len() {
+        for j in (i + 1)..locs.len() {
+            part_one = part_one.max(area(&locs[i], &locs[j]));
+        }
+    }
+
+    debug!(tiles = locs.len(), largest = %part_one, "rectangles measured");
+    part_one
+}
+
+/// The area of the largest rectangle with red tiles in opposite corners that
+/// lies entirely inside the loop of red and green tiles.
+pub fn solve_part_two(locs: &[Location]) -> u128 {
+    let _span = debug_span!("inside").entered();
+    let floor = Floor::new(locs);
+    let mut part_two = 0;
+
+    for i in 0..locs.len() {
+        for j in (i + 1)..locs.len() {
+            let area = area(&locs[i], &locs[j]);
+            if area > part_two && floor.covers(&locs[i], &locs[j]) {
+                trace!(a = ?locs[i], b = ?locs[j], %area, "larger rectangle");
+                part_two = area;
+            }
+        }
+    }
+
+    debug!(largest = %part_two, "rectangles measured");
+    part_two
+}
+
+/// The [`Solution`] for day 9, as run by `aoc run --day 9`.
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    const TITLE: &'static str = "Movie Theater";
+    const INPUT_SIZE: usize = 496;
+
+    type Input = Vec<Location>;
+    type PartOne = u128;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(locs: &Self::Input, _params: &Params) -> Self::PartOne {
+        solve_part_one(locs)
+    }
+
+    fn part_two(locs: &Self::Input, _params: &Params) -> Option<Self::PartTwo> {
+        Some(solve_part_two(locs))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use proptest::prelude::{Strategy, prop, prop_assert_eq, proptest};
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3
+";
+
+    #[test]
+    fn example_polygon() {
+        let floor = Floor::new(&parse_input(EXAMPLE).unwrap());
+        for pt in [(8, 2), (10, 6), (3, 4), (8, 4), (7, 1), (11, 4)] {
+            assert!(floor.contains(&pt), "{:?} should be inside", pt);
+        }
+        for pt in [(3, 7), (5, 2), (12, 4), (0, 0), (10, 8)] {
+            assert!(!floor.contains(&pt), "{:?} should be outside", pt);
+        }
+    }
+
+    #[test]
+    fn rows_through_corners() {
+        // the rows of these points pass through corners of the loop
+        let locs = vec![(1, 6), (3, 6), (3, 0), (1, 0)];
+        let floor = Floor::new(&locs);
+        for pt in [(2, 1), (2, 0), (1, 3), (3, 6)] {
+            assert!(floor.contains(&pt), "{:?} should be inside", pt);
+        }
+        for pt in [(0, 0), (0, 6), (4, 3), (2, 7)] {
+            assert!(!floor.contains(&pt), "{:?} should be outside", pt);
+        }
+        assert_eq!(solve_part_two(&locs), 21);
+    }
+
+    #[test]
+    fn thin_rectangles() {
+        // two columns side by side; the rectangle from (1, 8) to (2, 0) has
+        // no tiles strictly inside it, but (1, 0) is outside the loop
+        let locs = vec![
+            (1, 8),
+            (2, 8),
+            (2, 6),
+            (3, 6),
+            (3, 0),
+            (2, 0),
+            (2, 2),
+            (1, 2),
+        ];
+        assert!(!Floor::new(&locs).covers(&(1, 8), &(2, 0)));
+        assert_eq!(solve_part_two(&locs), 15);
+    }
+
+    #[test]
+    fn example() {
+        let locs = parse_input(EXAMPLE).unwrap();
+        assert_eq!(solve_part_one(&locs), 50);
+        assert_eq!(solve_part_two(&locs), 24);
+    }
+
+    #[test]
+    fn first_two_locations() {
+        // the largest rectangle uses the first pair of red tiles
+        let locs = vec![(0, 0), (9, 9), (9, 0), (0, 9)];
+        assert_eq!(solve_part_one(&locs), 100);
+    }
+
+    #[test]
+    fn far_apart() {
+        // a square 2^32 tiles on a side has an area beyond 64 bits
+        let far = 1i64 << 32;
+        let locs = vec![(0, 0), (far - 1, 0), (far - 1, far - 1), (0, far - 1)];
+        assert_eq!(solve_part_one(&locs), 1 << 64);
+        assert_eq!(solve_part_two(&locs), 1 << 64);
+
+        // and the largest the floor can hold still fits
+        let locs = vec![
+            (-MAX_COORD, -MAX_COORD),
+            (MAX_COORD, -MAX_COORD),
+            (MAX_COORD, MAX_COORD),
+            (-MAX_COORD, MAX_COORD),
+        ];
+        assert_eq!(
+            solve_part_two(&locs),
+            (1u128 << 63) * (1u128 << 63) - (1u128 << 64) + 1
+        );
+    }
+
+    #[test]
+    fn coordinate_too_far() {
+        let err = parse_input(&format!("0,0\n{},0\n", MAX_COORD + 1)).unwrap_err();
+        assert_eq!(err.line(), 2);
+        assert!(parse_input(&format!("0,0\n{},0\n", -MAX_COORD)).is_ok());
+    }
+
+    // a loop like the generator's, a row of columns with ragged tops and
+    // bottoms, small enough to fill in tile by tile
+    fn small_loop() -> impl Strategy<Value = Vec<Location>> {
+        (1..=4usize)
+            .prop_flat_map(|num_cols| {
+                (
+                    prop::collection::vec(1..4i64, num_cols + 1),
+                    prop::collection::vec(6..10i64, num_cols),
+                    prop::collection::vec(0..4i64, num_cols),
+                )
+            })
+            .prop_filter(
+                "neighbouring columns share a height",
+                |(_, tops, bottoms)| {
+                    tops.windows(2).all(|w| w[0] != w[1])
+                        && bottoms.windows(2).all(|w| w[0] != w[1])
+                },
+            )
+            .prop_map(|(gaps, tops, bottoms)| {
+                let xs = gaps
+                    .iter()
+                    .scan(0, |x, gap| {
+                        *x += gap;
+                        Some(*x)
+                    })
+                    .collect::<Vec<_>>();
+                let mut locs = vec![];
+                for i in 0..tops.len() {
+                    locs.push((xs[i], tops[i]));
+                    locs.push((xs[i + 1], tops[i]));
+                }
+                for i in (0..bottoms.len()).rev() {
+                    locs.push((xs[i + 1], bottoms[i]));
+                    locs.push((xs[i], bottoms[i]));
+                }
+                locs
+            })
+    }
+
+    // colours in the loop and everything inside it a tile at a time, then
+    // checks every tile of every rectangle
+    fn largest_by_raster(locs: &[Location]) -> u128 {
+        let mut tiles = HashSet::new();
+        for k in 0..locs.len() {
+            let (a, b) = (locs[k], locs[(k + 1) % locs.len()]);
+            for x in a.0.min(b.0)..=a.0.max(b.0) {
+                for y in a.1.min(b.1)..=a.1.max(b.1) {
+                    tiles.insert((x, y));
+                }
+            }
+        }
+
+        let max_x = locs.iter().map(|loc| loc.0).max().unwrap();
+        let max_y = locs.iter().map(|loc| loc.1).max().unwrap();
+        for y in 0..=max_y {
+            let mut inside = false;
+            for x in 0..=max_x {
+                // crossing a vertical edge that covers the bottom half of
+                // this row's tiles flips between outside and inside
+                let crossed = (0..locs.len()).any(|k| {
+                    let (a, b) = (locs[k], locs[(k + 1) % locs.len()]);
+                    a.0 == x && b.0 == x && a.1.min(b.1) <= y && y < a.1.max(b.1)
+                });
+                if crossed {
+                    inside = !inside;
+                }
+                if inside {
+                    tiles.insert((x, y));
+                }
+            }
+        }
+
+        let mut largest = 0;
+        for (i, a) in locs.iter().enumerate() {
+            for b in locs[(i + 1)..].iter() {
+                let filled = (a.0.min(b.0)..=a.0.max(b.0))
+                    .all(|x| (a.1.min(b.1)..=a.1.max(b.1)).all(|y| tiles.contains(&(x, y))));
+                if filled {
+                    largest = largest.max(area(a, b));
+                }
+            }
+        }
+        largest
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_filling_tiles(locs in small_loop()) {
+            prop_assert_eq!(solve_part_two(&locs), largest_by_raster(&locs));
+        }
+    }
+}
@@ -0,0 +1,584 @@
+//! Day 10: Factory. Finding the fewest button presses to configure each
+//! machine's lights and joltage counters.
+
+use std::collections::{HashSet, VecDeque};
+
+use aoc_common::{Answer, Params, ParseError, Rng, Solution, parse_token};
+use tracing::{debug, trace, trace_span};
+
+/// A machine's indicator lights, buttons and joltage requirements. Lights
+/// and buttons are bit masks, with light `i` in bit `i`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Machine {
+    light_target: u32,
+    buttons: Vec<u32>,
+    press_targets: Vec<u32>,
+}
+
+// strips the brackets from a token like [.##.], (1,3) or {3,5,4,7}
+fn delimited<'a>(
+    input: &str,
+    token: &'a str,
+    open: char,
+    close: char,
+    expected: &str,
+) -> Result<&'a str, ParseError> {
+    token
+        .strip_prefix(open)
+        .and_then(|rest| rest.strip_suffix(close))
+        .ok_or_else(|| ParseError::at(input, token, expected))
+}
+
+/// Parses one machine per line, like `[.##.] (3) (1,3) (2) {3,5,4,7}`.
+pub fn parse_input(input: &str) -> Result<Vec<Machine>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let pcs = line.split_ascii_whitespace().collect::<Vec<_>>();
+            if pcs.len() < 2 {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "a machine like [.##.] (3) (1,3) {3,5,4,7}",
+                ));
+            }
+
+            let lights = delimited(
+                input,
+                pcs[0],
+                '[',
+                ']',
+                "an indicator light diagram like [.##.]",
+            )?;
+            let num_lights = lights.chars().count();
+            if num_lights > 32 {
+                return Err(ParseError::at(input, pcs[0], "at most 32 indicator lights"));
+            }
+            let light_target = lights
+                .char_indices()
+                .enumerate()
+                .map(|(i, (idx, c))| match c {
+                    '#' => Ok(1 << i),
+                    '.' => Ok(0),
+                    _ => Err(ParseError::at(
+                        input,
+                        &lights[idx..(idx + c.len_utf8())],
+                        "a light that is on, #, or off, .",
+                    )),
+                })
+                .sum::<Result<u32, _>>()?;
+
+            let buttons = pcs[1..(pcs.len() - 1)]
+                .iter()
+                .map(|switch| {
+                    delimited(input, switch, '(', ')', "a button like (1,3)")?
+                        .split(',')
+                        .try_fold(0, |acc, seg| {
+                            let light = parse_token::<u32>(input, seg, "a light index")?;
+                            if light as usize >= num_lights {
+                                return Err(ParseError::at(
+                                    input,
+                                    seg,
+                                    format!("a light index below {}", num_lights),
+                                ));
+                            }
+                            Ok(acc | (1 << light))
+                        })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let press_targets = pcs[pcs.len() - 1];
+            let press_targets = delimited(
+                input,
+                press_targets,
+                '{',
+                '}',
+                "joltage requirements like {3,5,4,7}",
+            )?
+            .split(',')
+            .map(|target| parse_token(input, target, "a joltage requirement"))
+            .collect::<Result<Vec<_>, _>>()?;
+            if press_targets.len() != num_lights {
+                return Err(ParseError::at(
+                    input,
+                    pcs[pcs.len() - 1],
+                    format!("{} joltage requirements", num_lights),
+                ));
+            }
+
+            Ok(Machine {
+                light_target,
+                buttons,
+                press_targets,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()
+}
+
+/// Writes `size` machines of 4 to 10 lights and 2 to 13 buttons. The lights
+/// and joltages come from pressing the buttons, so every machine can be
+/// configured.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let num_lights = rng.between(4, 10) as usize;
+            // a few more buttons than lights at most, like the real input. the
+            // more spare buttons there are, the longer the search takes
+            let num_buttons = rng.between(num_lights as u64 - 2, num_lights as u64 + 3);
+
+            let buttons = (0..num_buttons)
+                .map(|_| {
+                    let mut lights = (0..num_lights)
+                        .filter(|_| rng.chance(0.4))
+                        .collect::<Vec<_>>();
+                    if lights.is_empty() {
+                        lights.push(rng.below(num_lights as u64) as usize);
+                    }
+                    lights
+                })
+                .collect::<Vec<_>>();
+
+            let mut lights_on = vec![false; num_lights];
+            let mut joltages = vec![0; num_lights];
+            for button in buttons.iter() {
+                let toggled = rng.chance(0.5);
+                let presses = rng.below(20);
+                for &light in button.iter() {
+                    lights_on[light] ^= toggled;
+                    joltages[light] += presses;
+                }
+            }
+
+            let lights = lights_on
+                .iter()
+                .map(|&on| if on { '#' } else { '.' })
+                .collect::<String>();
+            let buttons = buttons
+                .iter()
+                .map(|button| {
+                    let lights = button.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+                    format!("({})", lights.join(","))
+                })
+                .collect::<Vec<_>>();
+            let joltages = joltages.iter().map(|j| j.to_string()).collect::<Vec<_>>();
+
+            format!(
+                "[{}] {} {{{}}}\n",
+                lights,
+                buttons.join(" "),
+                joltages.join(",")
+            )
+        })
+        .collect()
+}
+
+impl Machine {
+    /// Creates a machine from the mask of lights that should be on, the mask
+    /// of lights each button toggles, and the joltage each counter should
+    /// reach.
+    pub fn new(light_target: u32, buttons: Vec<u32>, press_targets: Vec<u32>) -> Self {
+        Machine {
+            light_target,
+            buttons,
+            press_targets,
+        }
+    }
+
+    /// The fewest button presses that turn on exactly the target lights.
+    ///
+    /// # Panics
+    ///
+    /// If no combination of buttons gives the target.
+    pub fn solve_part_one(&self) -> u32 {
+        let _span = trace_span!("lights", target = self.light_target).entered();
+
+        let mut seen = HashSet::new();
+
+        let mut to_visit = VecDeque::new();
+        to_visit.push_back((0, 0));
+
+        let mut level = 0;
+        while let Some(next) = to_visit.pop_front() {
+            let (state, depth) = next;
+            if seen.contains(&state) {
+                continue;
+            }
+            seen.insert(state);
+
+            if depth > level {
+                level = depth;
+                trace!(
+                    depth,
+                    seen = seen.len(),
+                    queued = to_visit.len(),
+                    "bfs depth"
+                );
+            }
+
+            if state == self.light_target {
+                debug!(presses = depth, seen = seen.len(), "lights configured");
+                return depth;
+            }
+
+            for button in self.buttons.iter() {
+                to_visit.push_back((state ^ button, depth + 1));
+            }
+        }
+
+        panic!()
+    }
+
+    /// The fewest button presses that raise every joltage counter to its
+    /// requirement, where each press adds one to the counters of the lights
+    /// the button is wired to.
+    ///
+    /// # Panics
+    ///
+    /// If no combination of presses meets the requirements.
+    pub fn solve_part_two(&self) -> u64 {
+        let _span = trace_span!("joltage", targets = ?self.press_targets).entered();
+
+        let num_targets = self.press_targets.len();
+
+        // lifts[i] is the button values that will increase slot i
+        let lifts = (0..num_targets)
+            .map(|i| {
+                self.buttons
+                    .iter()
+                    .filter_map(|&button| {
+                        if ((1 << i) & button) > 0 {
+                            Some(button)
+                        } else {
+                            None
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut allowed_buttons = self.buttons.iter().cloned().collect::<HashSet<_>>();
+
+        // this will be a list of lists of trial runs indicating the number of times
+        // that we've pressed each button
+        let mut combos = vec![(vec![0; num_targets], 0u64)];
+
+        // fill up each slot 1 by 1
+        let mut to_fill = (0..num_targets).collect::<HashSet<_>>();
+        loop {
+            if to_fill.is_empty() {
+                break;
+            }
+
+            // the slot that we'll fill this one is the one with the least number
+            // of available slots. in the even of a tie, the one with the smaller
+            // fill amount
+            let target = to_fill
+                .iter()
+                .map(|&i| {
+                    let lifts = lifts[i]
+                        .iter()
+                        .filter(|b| allowed_buttons.contains(b))
+                        .count();
+                    (i, lifts, self.press_targets[i])
+                })
+                .min_by(|a, b| a.1.cmp(&b.1).then(a.2.cmp(&b.2)))
+                .unwrap()
+                .0;
+            to_fill.remove(&target);
+
+            let allowed_lifts = lifts[target]
+                .iter()
+                .filter(|b| allowed_buttons.contains(b))
+                .collect::<Vec<_>>();
+
+            let mut new_combos = vec![];
+
+            // for each combo that we've built so far, extend in all the ways that we can
+            // hit the remaining target
+            for (combo, count) in combos.iter() {
+                if combo[target] == self.press_targets[target] {
+                    new_combos.push((combo.clone(), *count));
+                    continue;
+                }
+
+                if allowed_lifts.is_empty() {
+                    continue;
+                }
+
+                if combo[target] > self.press_targets[target] {
+                    // we should have filtered this out
+                    unreachable!()
+                }
+
+                let rest = self.press_targets[target] - combo[target];
+
+                // lift counts will be a list of all of the different ways
+                // to lift the target we're working on to the goal
+                let mut lift_counts = vec![vec![0u32; allowed_lifts.len()]];
+
+                for lift_idx in 1..allowed_lifts.len() {
+                    let mut new_lift_counts: Vec<Vec<u32>> = vec![];
+                    for lift_count in lift_counts.iter() {
+                        let rest_rest = rest - lift_count.iter().sum::<u32>();
+                        for press in 0..=rest_rest {
+                            let mut new_lift_count = lift_count.clone();
+                            new_lift_count[lift_idx] = press;
+                            new_lift_counts.push(new_lift_count);
+                        }
+                    }
+                    lift_counts = new_lift_counts;
+                }
+
+                for lift_count in lift_counts.iter_mut() {
+                    let rest_rest = rest - lift_count.iter().sum::<u32>();
+                    lift_count[0] = rest_rest;
+                }
+
+                let new_count = count + rest as u64;
+
+                // for each of the lift counts, fold that into the combo,
+                // making sure to handle any side effects
+                'lift_count_loop: for lift_count in lift_counts.iter() {
+                    let mut new_combo = combo.clone();
+                    for (lift_idx, lift_qty) in lift_count.iter().enumerate() {
+                        let button = allowed_lifts[lift_idx];
+                        for (t, slot) in new_combo.iter_mut().enumerate() {
+                            if ((1 << t) & button) > 0 {
+                                *slot += lift_qty;
+                                if *slot > self.press_targets[t] {
+                                    continue 'lift_count_loop;
+                                }
+                            }
+                        }
+                    }
+                    new_combos.push((new_combo, new_count));
+                }
+            }
+
+            trace!(
+                slot = target,
+                lifts = allowed_lifts.len(),
+                combos = new_combos.len(),
+                "filled slot"
+            );
+            if new_combos.is_empty() {
+                panic!("Can't solve")
+            }
+            combos = new_combos;
+
+            // we can never raise target again, so take that out of consideration
+            // to reduce complexity
+            allowed_buttons = allowed_buttons
+                .iter()
+                .cloned()
+                .filter(|b| !allowed_lifts.contains(&b))
+                .collect();
+        }
+
+        let presses = *combos
+            .iter()
+            .filter_map(|(combo, pushes)| {
+                if (0..num_targets).all(|i| combo[i] == self.press_targets[i]) {
+                    Some(pushes)
+                } else {
+                    None
+                }
+            })
+            .min()
+            .unwrap();
+        debug!(presses, combos = combos.len(), "joltage configured");
+        presses
+    }
+}
+
+/// The fewest presses to configure the lights of every machine.
+pub fn solve_part_one(machines: &[Machine]) -> Answer {
+    machines.iter().map(|m| m.solve_part_one()).sum()
+}
+
+/// The fewest presses to configure the joltage of every machine.
+pub fn solve_part_two(machines: &[Machine]) -> Answer {
+    machines.iter().map(|m| m.solve_part_two()).sum()
+}
+
+/// The [`Solution`] for day 10, as run by `aoc run --day 10`.
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    const TITLE: &'static str = "Factory";
+    const INPUT_SIZE: usize = 180;
+
+    type Input = Vec<Machine>;
+    type PartOne = Answer;
+    type PartTwo = Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(machines: &Self::Input, _params: &Params) -> Self::PartOne {
+        solve_part_one(machines)
+    }
+
+    fn part_two(machines: &Self::Input, _params: &Params) -> Option<Self::PartTwo> {
+        Some(solve_part_two(machines))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, VecDeque};
+
+    use proptest::prelude::{Strategy, any, prop, prop_assert_eq, proptest};
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}
+";
+
+    #[test]
+    fn fewest_presses_for_lights() {
+        let machines = parse_input(EXAMPLE).unwrap();
+        let presses = machines
+            .iter()
+            .map(Machine::solve_part_one)
+            .collect::<Vec<_>>();
+        assert_eq!(presses, vec![2, 3, 2]);
+    }
+
+    #[test]
+    fn fewest_presses_for_joltage() {
+        let machines = parse_input(EXAMPLE).unwrap();
+        let presses = machines
+            .iter()
+            .map(Machine::solve_part_two)
+            .collect::<Vec<_>>();
+        assert_eq!(presses, vec![10, 12, 11]);
+    }
+
+    #[test]
+    fn nothing_to_do() {
+        let machines = parse_input("[...] (0,1) (2) {0,0,0}").unwrap();
+        assert_eq!(machines[0].solve_part_one(), 0);
+        assert_eq!(machines[0].solve_part_two(), 0);
+    }
+
+    #[test]
+    fn example() {
+        let machines = parse_input(EXAMPLE).unwrap();
+        assert_eq!(solve_part_one(&machines), Answer::from(7u64));
+        assert_eq!(solve_part_two(&machines), Answer::from(33u64));
+    }
+
+    // a machine with up to four lights and five buttons, with targets made by
+    // pressing the buttons so that it can always be configured
+    fn small_machine() -> impl Strategy<Value = Machine> {
+        (1..=4u32)
+            .prop_flat_map(|num_lights| {
+                prop::collection::vec((1..(1u32 << num_lights), any::<bool>(), 0..4u32), 1..=5)
+                    .prop_map(move |buttons| (num_lights, buttons))
+            })
+            .prop_map(|(num_lights, buttons)| {
+                let mut light_target = 0;
+                let mut press_targets = vec![0; num_lights as usize];
+                for &(button, toggled, presses) in buttons.iter() {
+                    if toggled {
+                        light_target ^= button;
+                    }
+                    for (light, target) in press_targets.iter_mut().enumerate() {
+                        if button & (1 << light) > 0 {
+                            *target += presses;
+                        }
+                    }
+                }
+                let buttons = buttons.iter().map(|&(button, _, _)| button).collect();
+                Machine::new(light_target, buttons, press_targets)
+            })
+    }
+
+    // tries every set of buttons, pressing each at most once
+    fn fewest_toggles(machine: &Machine) -> u32 {
+        (0..(1u32 << machine.buttons.len()))
+            .filter(|pressed| {
+                let lights = (0..machine.buttons.len())
+                    .filter(|b| pressed & (1 << b) > 0)
+                    .fold(0, |lights, b| lights ^ machine.buttons[b]);
+                lights == machine.light_target
+            })
+            .map(|pressed| pressed.count_ones())
+            .min()
+            .unwrap()
+    }
+
+    // searches every count of every counter, one press at a time
+    fn fewest_presses(machine: &Machine) -> u64 {
+        let start = vec![0; machine.press_targets.len()];
+        let mut presses = HashMap::from([(start.clone(), 0)]);
+        let mut to_visit = VecDeque::from([start]);
+        while let Some(counters) = to_visit.pop_front() {
+            let count = presses[&counters];
+            if counters == machine.press_targets {
+                return count;
+            }
+            for &button in machine.buttons.iter() {
+                let mut next = counters.clone();
+                for (light, counter) in next.iter_mut().enumerate() {
+                    if button & (1 << light) > 0 {
+                        *counter += 1;
+                    }
+                }
+                let in_range = next
+                    .iter()
+                    .zip(machine.press_targets.iter())
+                    .all(|(c, t)| c <= t);
+                if in_range && !presses.contains_key(&next) {
+                    presses.insert(next.clone(), count + 1);
+                    to_visit.push_back(next);
+                }
+            }
+        }
+        panic!("no way to configure {:?}", machine)
+    }
+
+    proptest! {
+        #[test]
+        fn lights_agree_with_trying_every_set(machine in small_machine()) {
+            prop_assert_eq!(machine.solve_part_one(), fewest_toggles(&machine));
+        }
+
+        #[test]
+        fn joltage_agrees_with_exhaustive_search(machine in small_machine()) {
+            prop_assert_eq!(machine.solve_part_two(), fewest_presses(&machine));
+        }
+    }
+
+    #[test]
+    fn light_out_of_range() {
+        let err = parse_input("[.#] (0,2) {1,1}").unwrap_err();
+        assert_eq!(err.found(), "2");
+        assert_eq!(err.expected(), "a light index below 2");
+    }
+
+    #[test]
+    fn wrong_number_of_requirements() {
+        let err = parse_input("[.#] (0,1) {1,1,1}").unwrap_err();
+        assert_eq!(err.expected(), "2 joltage requirements");
+    }
+
+    #[test]
+    fn generated_machines_can_be_configured() {
+        let machines = parse_input(&generate(&mut Rng::new(1), 20)).unwrap();
+        assert_eq!(machines.len(), 20);
+        solve_part_one(&machines);
+        solve_part_two(&machines);
+    }
+}
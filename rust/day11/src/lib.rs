@@ -0,0 +1,534 @@
+//! Day 11: Reactor. Counting the paths data can take through the devices.
+
+use std::collections::HashMap;
+use std::iter;
+
+use aoc_common::{Error, ParamError, Params, ParseError, Rng, Solution};
+use tracing::{debug, debug_span, trace};
+
+/// The network of devices, each with the devices its outputs lead to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reactor {
+    out_edges: Vec<Vec<usize>>,
+    in_edges: Vec<Vec<usize>>,
+    ids: HashMap<String, usize>,
+}
+
+/// Parses one device per line with the devices it outputs to, like
+/// `aaa: you hhh`.
+pub fn parse_input(input: &str) -> Result<Reactor, ParseError> {
+    let mut all_ids = HashMap::<String, usize>::new();
+    let mut out_edges = vec![];
+    let mut in_edges = vec![];
+
+    for line in input.trim().lines() {
+        let (device, outputs) = line
+            .split_once(':')
+            .ok_or_else(|| ParseError::at(input, line, "a device like aaa: you hhh"))?;
+        let device = device.trim();
+        if device.is_empty() || device.contains(char::is_whitespace) {
+            return Err(ParseError::at(input, device, "a device name"));
+        }
+
+        let nodes = iter::once(device).chain(outputs.split_ascii_whitespace());
+        let mut ids = vec![];
+        for node in nodes {
+            let id = {
+                if let Some(id) = all_ids.get(node) {
+                    *id
+                } else {
+                    all_ids.insert(String::from(node), out_edges.len());
+                    out_edges.push(vec![]);
+                    in_edges.push(vec![]);
+                    all_ids[node]
+                }
+            };
+            ids.push(id);
+        }
+        out_edges[ids[0]] = ids[1..].to_vec();
+        for &id in ids.iter().skip(1) {
+            in_edges[id].push(ids[0]);
+        }
+    }
+
+    Ok(Reactor {
+        in_edges,
+        out_edges,
+        ids: all_ids,
+    })
+}
+
+// the devices the puzzle asks about, in the order they appear in generated
+// networks. random names must avoid them
+const NAMED_DEVICES: [&str; 5] = ["svr", "you", "fft", "dac", "out"];
+
+// three letter names while they last, then longer ones
+fn device_name(mut i: usize) -> String {
+    let mut len = 3;
+    while i >= 26usize.pow(len) {
+        i -= 26usize.pow(len);
+        len += 1;
+    }
+    (0..len)
+        .rev()
+        .map(|k| char::from(b'a' + (i / 26usize.pow(k) % 26) as u8))
+        .collect()
+}
+
+/// Writes a network of `size` devices, at least six, with no cycles. Every
+/// device leads to `out`, through `svr`, `you`, `fft` and `dac` in that
+/// order.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let n = size.max(6);
+
+    // devices are in an order that every output follows
+    let mut names = (0..)
+        .map(device_name)
+        .filter(|name| !NAMED_DEVICES.contains(&name.as_str()))
+        .take(n)
+        .collect::<Vec<_>>();
+    // 0 < n/6 < n/3 < 2n/3 < n - 1 for every n from six up, so each named
+    // device gets a place of its own
+    let (svr, you, fft, dac, out) = (0, n / 6, n / 3, 2 * n / 3, n - 1);
+    for (i, name) in [svr, you, fft, dac, out].into_iter().zip(NAMED_DEVICES) {
+        names[i] = String::from(name);
+    }
+
+    // outputs only reach a little way ahead, which keeps the paths long and
+    // their count within reason
+    let reach = (n / 8).max(2);
+    let mut outputs = (0..(n - 1))
+        .map(|i| {
+            // up to three different devices, drawn straight from the window
+            // ahead rather than shuffling all of it
+            let last = (i + reach).min(out);
+            let count = (rng.between(1, 3) as usize).min(last - i);
+            let mut later = Vec::with_capacity(count);
+            while later.len() < count {
+                let next = rng.between(i as u64 + 1, last as u64) as usize;
+                if !later.contains(&next) {
+                    later.push(next);
+                }
+            }
+            later
+        })
+        .collect::<Vec<_>>();
+
+    // make sure there is at least one path from svr through fft and dac
+    let mut i = svr;
+    for stop in [fft, dac, out] {
+        while i < stop {
+            let next = (i + rng.between(1, reach as u64) as usize).min(stop);
+            if !outputs[i].contains(&next) {
+                outputs[i].push(next);
+            }
+            i = next;
+        }
+    }
+
+    let mut lines = outputs
+        .iter()
+        .enumerate()
+        .map(|(i, later)| {
+            let later = later.iter().map(|&j| names[j].as_str()).collect::<Vec<_>>();
+            format!("{}: {}\n", names[i], later.join(" "))
+        })
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut lines);
+
+    lines.concat()
+}
+
+impl Reactor {
+    /// Counts the paths from `source` to `target` that visit every device in
+    /// `reqs`. The network must have no cycles.
+    ///
+    /// # Panics
+    ///
+    /// If any of the devices named is not in the network, or a path from
+    /// `source` goes round a cycle.
+    pub fn solve(&self, source: &str, target: &str, reqs: &[&str]) -> u64 {
+        let _span = debug_span!("paths", source, target, ?reqs).entered();
+
+        let id = |name: &str| {
+            *self
+                .ids
+                .get(name)
+                .unwrap_or_else(|| panic!("No device named {}", name))
+        };
+        let source = id(source);
+        let target = id(target);
+        let mut reqs = reqs.iter().map(|&r| id(r)).collect::<Vec<_>>();
+        reqs.sort_unstable();
+        reqs.dedup();
+
+        // for each device, the paths from it to the target by which of the
+        // requirements they visit, as a bitmask. a device's outputs are
+        // counted before it, working back from the deepest
+        let masks = 1 << reqs.len();
+        let mut path_counts: Vec<Option<Vec<u64>>> = vec![None; self.out_edges.len()];
+        let mut entered = vec![false; self.out_edges.len()];
+        let mut to_visit = vec![source];
+
+        while let Some(&next) = to_visit.last() {
+            if path_counts[next].is_some() {
+                to_visit.pop();
+                continue;
+            }
+
+            // the paths stop at the target, so nothing after it counts
+            if !entered[next] && next != target {
+                entered[next] = true;
+                for &head in self.out_edges[next].iter() {
+                    // entered but not yet counted is still waiting on us
+                    assert!(
+                        path_counts[head].is_some() || !entered[head],
+                        "The network has a cycle through device {}",
+                        head
+                    );
+                    if path_counts[head].is_none() {
+                        to_visit.push(head);
+                    }
+                }
+                trace!(device = next, queued = to_visit.len(), "waiting on outputs");
+                continue;
+            }
+            to_visit.pop();
+
+            let mut count = vec![0u64; masks];
+            if next == target {
+                count[0] = 1;
+            } else {
+                for &head in self.out_edges[next].iter() {
+                    let head = path_counts[head]
+                        .as_ref()
+                        .expect("outputs are counted first");
+                    for (total, paths) in count.iter_mut().zip(head) {
+                        *total += paths;
+                    }
+                }
+            }
+
+            // every path from a requirement visits it
+            if let Some(i) = reqs.iter().position(|&r| r == next) {
+                let bit = 1 << i;
+                for mask in (0..masks).filter(|mask| mask & bit == 0) {
+                    count[mask | bit] += count[mask];
+                    count[mask] = 0;
+                }
+            }
+
+            path_counts[next] = Some(count);
+        }
+
+        let counted = path_counts.iter().flatten().count();
+        debug!(counted, "counted paths");
+        path_counts[source]
+            .as_ref()
+            .map_or(0, |count| count[masks - 1])
+    }
+}
+
+/// Where part one's paths start and end.
+pub const PART_ONE_ROUTE: (&str, &str) = ("you", "out");
+/// Where part two's paths start and end, and the devices they must visit.
+pub const PART_TWO_ROUTE: (&str, &str, [&str; 2]) = ("svr", "out", ["dac", "fft"]);
+
+/// The most devices `--via` can name. Every device keeps a count for each
+/// set of them a path can visit, so each one more doubles the counts.
+pub const MAX_VIA: usize = 12;
+
+// --from and --to apply to both parts, --via only to part two
+fn route(params: &Params, default: (&str, &str)) -> Result<(String, String), ParamError> {
+    let from = params
+        .get("from")?
+        .unwrap_or_else(|| String::from(default.0));
+    let to = params.get("to")?.unwrap_or_else(|| String::from(default.1));
+    Ok((from, to))
+}
+
+fn via(params: &Params) -> Result<Vec<String>, ParamError> {
+    Ok(params
+        .get_list("via")?
+        .unwrap_or_else(|| PART_TWO_ROUTE.2.map(String::from).to_vec()))
+}
+
+/// The [`Solution`] for day 11, as run by `aoc run --day 11`.
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    const TITLE: &'static str = "Reactor";
+    const INPUT_SIZE: usize = 600;
+
+    type Input = Reactor;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    /// Every device a part's paths start at, end at or visit has to be in
+    /// the network.
+    fn check(
+        _: &str,
+        reactor: &Self::Input,
+        part: Option<u8>,
+        params: &Params,
+    ) -> Result<(), Error> {
+        let (source, target, _) = PART_TWO_ROUTE;
+        let mut devices = vec![];
+        if part.is_none_or(|part| part == 1) {
+            let (from, to) = route(params, PART_ONE_ROUTE)?;
+            devices.extend([("from", from), ("to", to)]);
+        }
+        if part.is_none_or(|part| part == 2) {
+            let (from, to) = route(params, (source, target))?;
+            devices.extend([("from", from), ("to", to)]);
+
+            let mut via = via(params)?;
+            via.sort_unstable();
+            via.dedup();
+            if via.len() > MAX_VIA {
+                return Err(ParamError::new(
+                    "via",
+                    format!(
+                        "can't visit more than {} devices, not {}",
+                        MAX_VIA,
+                        via.len()
+                    ),
+                )
+                .into());
+            }
+            devices.extend(via.into_iter().map(|device| ("via", device)));
+        }
+
+        match devices
+            .into_iter()
+            .find(|(_, device)| !reactor.ids.contains_key(device))
+        {
+            Some((name, device)) => {
+                Err(ParamError::new(name, format!("there is no device named {}", device)).into())
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn part_one(reactor: &Self::Input, params: &Params) -> Self::PartOne {
+        let (from, to) = route(params, PART_ONE_ROUTE).expect("checked before solving");
+        reactor.solve(&from, &to, &[])
+    }
+
+    fn part_two(reactor: &Self::Input, params: &Params) -> Option<Self::PartTwo> {
+        let (source, target, _) = PART_TWO_ROUTE;
+        let (from, to) = route(params, (source, target)).expect("checked before solving");
+        let via = via(params).expect("checked before solving");
+        let via = via.iter().map(String::as_str).collect::<Vec<_>>();
+        Some(reactor.solve(&from, &to, &via))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::{Strategy, prop, prop_assert_eq, proptest};
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+aaa: you hhh
+you: bbb ccc
+bbb: ddd eee
+ccc: ddd eee fff
+ddd: ggg
+eee: out
+fff: out
+ggg: out
+hhh: ccc fff iii
+iii: out
+";
+
+    const EXAMPLE_PART_TWO: &str = "\
+svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out
+";
+
+    #[test]
+    fn paths_out() {
+        let reactor = parse_input(EXAMPLE).unwrap();
+        assert_eq!(reactor.solve("you", "out", &[]), 5);
+        assert_eq!(reactor.solve("hhh", "out", &[]), 5);
+        assert_eq!(reactor.solve("eee", "out", &[]), 1);
+    }
+
+    #[test]
+    fn paths_through_required_devices() {
+        let reactor = parse_input(EXAMPLE_PART_TWO).unwrap();
+        assert_eq!(reactor.solve("svr", "out", &[]), 8);
+        assert_eq!(reactor.solve("svr", "out", &["fft"]), 4);
+        assert_eq!(reactor.solve("svr", "out", &["dac", "fft"]), 2);
+        assert_eq!(reactor.solve("svr", "out", &["fft", "dac"]), 2);
+    }
+
+    #[test]
+    fn unreachable_requirement() {
+        let reactor = parse_input(EXAMPLE_PART_TWO).unwrap();
+        // nothing after fff can reach fft
+        assert_eq!(reactor.solve("fff", "out", &["fft"]), 0);
+        // and nothing leads back to svr
+        assert_eq!(reactor.solve("out", "svr", &[]), 0);
+    }
+
+    #[test]
+    fn dead_ends_and_more_requirements() {
+        // only one of svr's outputs leads anywhere
+        let reactor = parse_input("svr: out dead\ndead: end\n").unwrap();
+        assert_eq!(reactor.solve("svr", "out", &[]), 1);
+
+        // there are paths from aaa through bbb and through ccc, but none
+        // through both
+        let reactor = parse_input("aaa: bbb ccc\nbbb: out\nccc: out\n").unwrap();
+        assert_eq!(reactor.solve("aaa", "out", &["aaa", "bbb", "ccc"]), 0);
+        assert_eq!(reactor.solve("aaa", "out", &["aaa", "bbb", "bbb"]), 1);
+    }
+
+    #[test]
+    fn route_params() {
+        let reactor = parse_input(EXAMPLE_PART_TWO).unwrap();
+        let params = |pairs: &[(&str, &str)]| {
+            Params::new(
+                pairs
+                    .iter()
+                    .map(|&(name, value)| (String::from(name), String::from(value))),
+            )
+        };
+
+        assert_eq!(Day11::part_two(&reactor, &params(&[])), Some(2));
+        assert_eq!(
+            Day11::part_two(&reactor, &params(&[("via", "fft")])),
+            Some(4)
+        );
+        assert_eq!(
+            Day11::part_one(&reactor, &params(&[("from", "svr"), ("to", "fff")])),
+            4
+        );
+
+        // this example has no `you`, so only part two can run
+        let check = |part, pairs: &[(&str, &str)]| {
+            Day11::check(EXAMPLE_PART_TWO, &reactor, part, &params(pairs))
+        };
+        assert!(check(Some(2), &[]).is_ok());
+        assert_eq!(
+            check(None, &[]).unwrap_err().to_string(),
+            "Invalid parameter from: there is no device named you"
+        );
+        assert!(check(Some(2), &[("via", "fft,nope")]).is_err());
+        assert!(check(Some(1), &[("from", "svr"), ("to", "nope")]).is_err());
+
+        let many = (0..=MAX_VIA).map(device_name).collect::<Vec<_>>().join(",");
+        assert!(
+            check(Some(2), &[("via", &many)])
+                .unwrap_err()
+                .to_string()
+                .contains("can't visit more than 12 devices, not 13")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "No device named abc")]
+    fn unknown_device() {
+        let reactor = parse_input(EXAMPLE).unwrap();
+        reactor.solve("abc", "out", &[]);
+    }
+
+    #[test]
+    fn missing_colon() {
+        let err = parse_input("aaa: bbb\nbbb ccc\n").unwrap_err();
+        assert_eq!(err.line(), 2);
+        assert_eq!(err.expected(), "a device like aaa: you hhh");
+    }
+
+    #[test]
+    fn generated_networks_have_paths() {
+        for size in (0..20).chain([50, 100]) {
+            for seed in 0..10 {
+                let reactor = parse_input(&generate(&mut Rng::new(seed), size)).unwrap();
+                assert!(reactor.solve("you", "out", &[]) > 0, "size {}", size);
+                assert!(reactor.solve("svr", "out", &["dac", "fft"]) > 0);
+            }
+        }
+    }
+
+    // a network of up to seven devices, each with outputs to any of the ones
+    // after it, and a mask of the devices between the first and last that
+    // paths have to visit
+    fn small_network() -> impl Strategy<Value = (Vec<Vec<usize>>, u32)> {
+        (2..=7usize).prop_flat_map(|n| {
+            (
+                prop::collection::vec(prop::collection::vec(prop::bool::ANY, n), n).prop_map(
+                    move |wired| {
+                        (0..n)
+                            .map(|i| ((i + 1)..n).filter(|&j| wired[i][j]).collect())
+                            .collect()
+                    },
+                ),
+                0..(1u32 << (n - 2)),
+            )
+        })
+    }
+
+    // follows every path from `device` to `target` one at a time
+    fn dfs(outputs: &[Vec<usize>], device: usize, target: usize, reqs: &[usize]) -> u64 {
+        let reqs = reqs
+            .iter()
+            .copied()
+            .filter(|&r| r != device)
+            .collect::<Vec<_>>();
+        if device == target {
+            return reqs.is_empty() as u64;
+        }
+        outputs[device]
+            .iter()
+            .map(|&next| dfs(outputs, next, target, &reqs))
+            .sum()
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_following_every_path((outputs, mask) in small_network()) {
+            let n = outputs.len();
+            let input = outputs
+                .iter()
+                .enumerate()
+                .map(|(i, later)| {
+                    let later = later.iter().map(|&j| device_name(j)).collect::<Vec<_>>();
+                    format!("{}: {}\n", device_name(i), later.join(" "))
+                })
+                .collect::<String>();
+            let reactor = parse_input(&input).unwrap();
+
+            let reqs = (1..(n - 1)).filter(|i| mask & (1 << (i - 1)) != 0).collect::<Vec<_>>();
+            let names = reqs.iter().map(|&r| device_name(r)).collect::<Vec<_>>();
+            let names = names.iter().map(String::as_str).collect::<Vec<_>>();
+            prop_assert_eq!(
+                reactor.solve(&device_name(0), &device_name(n - 1), &names),
+                dfs(&outputs, 0, n - 1, &reqs)
+            );
+        }
+    }
+}
@@ -0,0 +1,12 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        // an error is fine, as long as it can be shown
+        if let Err(err) = day04::parse_input(input) {
+            let _ = err.to_string();
+        }
+    }
+});